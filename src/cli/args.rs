@@ -0,0 +1,306 @@
+use super::ConfigCommand;
+use crate::config::layers::{self, Origin, Override};
+use anyhow::Result;
+use std::path::PathBuf;
+
+pub const USAGE: &str = "\
+사용법: auto_mouse [옵션]
+        auto_mouse [옵션] run -- <명령> [인자...]
+        auto_mouse snooze <분>
+        auto_mouse config convert <json|toml|yaml>
+        auto_mouse [옵션] config show [--origin]
+        auto_mouse [옵션] config export <파일> [--profiles] [--schedule] [--rules] [--all]
+        auto_mouse [옵션] config import <파일> [--yes]
+
+명령:
+  run -- <명령> [인자...]  창 없이 명령을 실행하고, 실행되는 동안 자리비움 방지
+                           (명령의 종료 코드로 종료)
+  snooze <분>              실행 중인 프로그램의 타이머를 지정한 분만큼 연장
+  config convert <형식>    설정 파일을 지정한 형식(json, toml, yaml)으로 변환
+                           (기존 파일은 .bak을 붙여 보관)
+  config show [--origin]   최종 설정값 출력 (--origin: 값을 정한 단계 함께 출력)
+  config export <파일>     설정을 공유할 수 있는 파일로 내보냄 (--profiles: 모든 프로필,
+                           --schedule: 시간표와 cron, --rules: 감시·전원·만료 동작,
+                           --all: 전부)
+  config import <파일>     내보낸 파일을 가져올 때 바뀌는 내용 출력
+                           (--yes: 실제로 적용, --profile: 적용할 프로필 지정)
+
+옵션:
+  --config <파일>      지정한 설정 파일 사용 (AUTO_MOUSE_CONFIG 환경 변수로도 지정 가능,
+                       프로필과 타이머 기록은 같은 디렉토리에 저장)
+  --profile <이름>     지정한 프로필의 설정으로 실행 (선택된 프로필은 바꾸지 않음)
+  --set <항목>=<값>    설정 항목을 이번 실행에만 덮어씀 (여러 번 사용 가능,
+                       예: --set power.pause_on_battery=true)
+  --watch <PID|패턴>   지정한 프로세스가 실행되는 동안에만 동작
+                       (숫자는 PID, 그 외는 이름 패턴이며 *, ? 사용 가능)
+  -h, --help           도움말 표시
+
+설정 파일은 --config, AUTO_MOUSE_CONFIG, 실행 파일 옆의 config.json/toml/yaml
+(포터블 모드), 사용자 설정 디렉토리 순으로 찾습니다.
+설정값은 기본값 < 설정 파일 < 환경 변수 < 명령줄 순으로 적용됩니다.
+환경 변수 이름은 AUTO_MOUSE_ 뒤에 항목 이름을 대문자로 쓰고 .은 __로 바꿉니다
+(예: AUTO_MOUSE_INTERVAL_SECONDS=20, AUTO_MOUSE_POWER__PAUSE_ON_BATTERY=true).";
+
+#[derive(Debug, Clone, Default)]
+pub struct CliArgs {
+    pub help: bool,
+    pub config: Option<PathBuf>,
+    pub profile: Option<String>,
+    /// `--set`으로 지정한 항목과 값 (지정한 순서대로 적용)
+    pub sets: Vec<(String, String)>,
+    pub watch: Option<String>,
+    pub command: Option<CliCommand>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CliCommand {
+    /// 명령을 감싸서 실행하는 동안만 동작
+    Run(Vec<String>),
+    /// 실행 중인 프로그램의 타이머 연장
+    Snooze(u32),
+    /// 설정 파일 관리
+    Config(ConfigCommand),
+}
+
+impl CliArgs {
+    /// 프로그램 이름을 제외한 명령줄 인자를 해석
+    pub fn parse<I>(args: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = String>,
+    {
+        let mut parsed = CliArgs::default();
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            let (name, inline_value) = match arg.split_once('=') {
+                Some((name, value)) if name.starts_with("--") => {
+                    (name.to_string(), Some(value.to_string()))
+                }
+                _ => (arg.clone(), None),
+            };
+
+            match name.as_str() {
+                "-h" | "--help" => parsed.help = true,
+                "--config" => {
+                    let value = inline_value.or_else(|| args.next()).ok_or_else(|| {
+                        anyhow::anyhow!("--config 옵션에 설정 파일 경로가 필요합니다")
+                    })?;
+                    parsed.config = Some(PathBuf::from(value));
+                }
+                "--profile" => {
+                    let value = inline_value.or_else(|| args.next()).ok_or_else(|| {
+                        anyhow::anyhow!("--profile 옵션에 프로필 이름이 필요합니다")
+                    })?;
+                    parsed.profile = Some(value);
+                }
+                "--set" => {
+                    let value = inline_value
+                        .or_else(|| args.next())
+                        .ok_or_else(|| anyhow::anyhow!("--set 옵션에 <항목>=<값>이 필요합니다"))?;
+                    parsed.sets.push(layers::parse_assignment(&value)?);
+                }
+                "--watch" => {
+                    let value = inline_value.or_else(|| args.next()).ok_or_else(|| {
+                        anyhow::anyhow!("--watch 옵션에 PID 또는 패턴이 필요합니다")
+                    })?;
+                    parsed.watch = Some(value);
+                }
+                "run" => {
+                    // `--` 이후의 인자는 모두 실행할 명령으로 취급
+                    let mut program: Vec<String> = args.by_ref().collect();
+                    if program.first().map(String::as_str) == Some("--") {
+                        program.remove(0);
+                    }
+                    if program.is_empty() {
+                        return Err(anyhow::anyhow!("run 명령에 실행할 명령이 필요합니다"));
+                    }
+                    parsed.command = Some(CliCommand::Run(program));
+                }
+                "snooze" => {
+                    let value = args.next().ok_or_else(|| {
+                        anyhow::anyhow!("snooze 명령에 연장할 시간(분)이 필요합니다")
+                    })?;
+                    let minutes = value
+                        .parse()
+                        .ok()
+                        .filter(|&minutes: &u32| minutes > 0)
+                        .ok_or_else(|| {
+                            anyhow::anyhow!("연장할 시간(분)이 올바르지 않습니다: {}", value)
+                        })?;
+                    parsed.command = Some(CliCommand::Snooze(minutes));
+                }
+                "config" => {
+                    parsed.command = Some(CliCommand::Config(ConfigCommand::parse(&mut args)?));
+                }
+                _ => return Err(anyhow::anyhow!("알 수 없는 인자: {}", arg)),
+            }
+        }
+
+        Ok(parsed)
+    }
+
+    /// 명령줄에서 지정한 설정값 (환경 변수보다 우선)
+    pub fn overrides(&self) -> Vec<Override> {
+        let mut overrides = Vec::new();
+        let mut push = |key: &str, value: String, flag: &str| {
+            overrides.push(Override {
+                key: key.to_string(),
+                value,
+                origin: Origin::Cli(flag.to_string()),
+            })
+        };
+
+        if let Some(target) = &self.watch {
+            push("process_watch.enabled", "true".to_string(), "--watch");
+            push("process_watch.target", target.clone(), "--watch");
+        }
+        for (key, value) in &self.sets {
+            push(key, value.clone(), "--set");
+        }
+        overrides
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::bundle::ExportOptions;
+
+    fn parse(args: &[&str]) -> Result<CliArgs> {
+        CliArgs::parse(args.iter().map(|arg| arg.to_string()))
+    }
+
+    fn error(args: &[&str]) -> String {
+        parse(args).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn options_take_separate_or_inline_values() {
+        let args = parse(&[
+            "--config",
+            "/a/work.json",
+            "--profile=야간",
+            "--set",
+            "interval_seconds=5",
+            "--set=power.pause_on_battery=true",
+            "--watch",
+            "ffmpeg*",
+        ])
+        .unwrap();
+
+        assert!(!args.help);
+        assert_eq!(args.config, Some(PathBuf::from("/a/work.json")));
+        assert_eq!(args.profile.as_deref(), Some("야간"));
+        assert_eq!(
+            args.sets,
+            vec![
+                ("interval_seconds".to_string(), "5".to_string()),
+                ("power.pause_on_battery".to_string(), "true".to_string()),
+            ]
+        );
+        assert_eq!(args.watch.as_deref(), Some("ffmpeg*"));
+        assert_eq!(args.command, None);
+        assert!(parse(&["-h"]).unwrap().help);
+
+        // --watch는 --set보다 먼저 적용되는 명령줄 값
+        let keys: Vec<String> = args.overrides().into_iter().map(|o| o.key).collect();
+        assert_eq!(
+            keys,
+            vec![
+                "process_watch.enabled",
+                "process_watch.target",
+                "interval_seconds",
+                "power.pause_on_battery",
+            ]
+        );
+    }
+
+    #[test]
+    fn run_passes_everything_after_double_dash() {
+        let args = parse(&["--profile", "빌드", "run", "--", "make", "-j4", "--watch"]).unwrap();
+        assert_eq!(args.profile.as_deref(), Some("빌드"));
+        assert_eq!(args.watch, None);
+        assert_eq!(
+            args.command,
+            Some(CliCommand::Run(vec![
+                "make".to_string(),
+                "-j4".to_string(),
+                "--watch".to_string(),
+            ]))
+        );
+
+        // `--` 없이 써도 나머지를 명령으로 취급
+        let args = parse(&["run", "sleep", "1"]).unwrap();
+        assert_eq!(
+            args.command,
+            Some(CliCommand::Run(vec!["sleep".to_string(), "1".to_string()]))
+        );
+
+        assert_eq!(error(&["run"]), "run 명령에 실행할 명령이 필요합니다");
+        assert_eq!(error(&["run", "--"]), "run 명령에 실행할 명령이 필요합니다");
+    }
+
+    #[test]
+    fn snooze_needs_positive_minutes() {
+        assert_eq!(
+            parse(&["snooze", "15"]).unwrap().command,
+            Some(CliCommand::Snooze(15))
+        );
+        assert_eq!(
+            error(&["snooze"]),
+            "snooze 명령에 연장할 시간(분)이 필요합니다"
+        );
+        assert_eq!(
+            error(&["snooze", "0"]),
+            "연장할 시간(분)이 올바르지 않습니다: 0"
+        );
+        assert_eq!(
+            error(&["snooze", "abc"]),
+            "연장할 시간(분)이 올바르지 않습니다: abc"
+        );
+    }
+
+    #[test]
+    fn config_command_follows_options() {
+        let args = parse(&[
+            "--config", "x.toml", "config", "export", "out.json", "--all",
+        ])
+        .unwrap();
+        assert_eq!(args.config, Some(PathBuf::from("x.toml")));
+        assert_eq!(
+            args.command,
+            Some(CliCommand::Config(ConfigCommand::Export {
+                path: PathBuf::from("out.json"),
+                options: ExportOptions::all(),
+            }))
+        );
+    }
+
+    #[test]
+    fn missing_values_and_unknown_arguments() {
+        assert_eq!(
+            error(&["--config"]),
+            "--config 옵션에 설정 파일 경로가 필요합니다"
+        );
+        assert_eq!(
+            error(&["--profile"]),
+            "--profile 옵션에 프로필 이름이 필요합니다"
+        );
+        assert_eq!(error(&["--set"]), "--set 옵션에 <항목>=<값>이 필요합니다");
+        assert_eq!(
+            error(&["--watch"]),
+            "--watch 옵션에 PID 또는 패턴이 필요합니다"
+        );
+        assert_eq!(
+            error(&["--set", "interval_seconds"]),
+            "<항목>=<값> 형식이어야 합니다: interval_seconds"
+        );
+        assert_eq!(
+            error(&["--set", "no_such_key=1"]),
+            "알 수 없는 설정 항목: no_such_key"
+        );
+        assert_eq!(error(&["--verbose"]), "알 수 없는 인자: --verbose");
+        assert_eq!(error(&["--verbose=1"]), "알 수 없는 인자: --verbose=1");
+        assert_eq!(error(&["start"]), "알 수 없는 인자: start");
+    }
+}
@@ -0,0 +1,334 @@
+use super::CliArgs;
+use crate::config::bundle::{Bundle, ExportOptions};
+use crate::config::{backup, layers};
+use crate::config::{
+    ConfigFormat, ConfigLocation, Overrides, ProfileStore, Settings, DEFAULT_PROFILE,
+};
+use anyhow::Result;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// 설정 파일을 다루는 명령
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigCommand {
+    /// 사용 중인 설정 파일을 다른 형식으로 변환
+    Convert(ConfigFormat),
+    /// 최종 설정값 출력 (`origin`이면 값을 정한 단계도 함께 출력)
+    Show { origin: bool },
+    /// 선택한 프로필의 설정(과 지정한 항목)을 파일 하나로 내보냄
+    Export {
+        path: PathBuf,
+        options: ExportOptions,
+    },
+    /// 내보낸 파일을 가져옴 (`yes`가 아니면 바뀌는 내용만 출력)
+    Import { path: PathBuf, yes: bool },
+}
+
+impl ConfigCommand {
+    /// `config` 뒤에 오는 인자를 해석
+    pub fn parse<I>(args: &mut I) -> Result<Self>
+    where
+        I: Iterator<Item = String>,
+    {
+        match args.next().as_deref() {
+            Some("convert") => {
+                let value = args.next().ok_or_else(|| {
+                    anyhow::anyhow!("config convert 명령에 형식(json, toml, yaml)이 필요합니다")
+                })?;
+                let format = ConfigFormat::from_name(&value)
+                    .ok_or_else(|| anyhow::anyhow!("알 수 없는 설정 형식: {}", value))?;
+                Ok(ConfigCommand::Convert(format))
+            }
+            Some("show") => {
+                let mut origin = false;
+                for arg in args.by_ref() {
+                    match arg.as_str() {
+                        "--origin" => origin = true,
+                        _ => return Err(anyhow::anyhow!("알 수 없는 인자: {}", arg)),
+                    }
+                }
+                Ok(ConfigCommand::Show { origin })
+            }
+            Some("export") => {
+                let mut path = None;
+                let mut options = ExportOptions::default();
+                for arg in args.by_ref() {
+                    match arg.as_str() {
+                        "--profiles" => options.profiles = true,
+                        "--schedule" => options.schedule = true,
+                        "--rules" => options.rules = true,
+                        "--all" => options = ExportOptions::all(),
+                        _ if path.is_none() && !arg.starts_with("--") => {
+                            path = Some(PathBuf::from(arg))
+                        }
+                        _ => return Err(anyhow::anyhow!("알 수 없는 인자: {}", arg)),
+                    }
+                }
+                let path = path.ok_or_else(|| {
+                    anyhow::anyhow!("config export 명령에 내보낼 파일 경로가 필요합니다")
+                })?;
+                Ok(ConfigCommand::Export { path, options })
+            }
+            Some("import") => {
+                let mut path = None;
+                let mut yes = false;
+                for arg in args.by_ref() {
+                    match arg.as_str() {
+                        "--yes" | "-y" => yes = true,
+                        _ if path.is_none() && !arg.starts_with('-') => {
+                            path = Some(PathBuf::from(arg))
+                        }
+                        _ => return Err(anyhow::anyhow!("알 수 없는 인자: {}", arg)),
+                    }
+                }
+                let path = path.ok_or_else(|| {
+                    anyhow::anyhow!("config import 명령에 가져올 파일 경로가 필요합니다")
+                })?;
+                Ok(ConfigCommand::Import { path, yes })
+            }
+            Some(other) => Err(anyhow::anyhow!("알 수 없는 config 명령: {}", other)),
+            None => Err(anyhow::anyhow!(
+                "config 명령이 필요합니다 (convert, show, export, import)"
+            )),
+        }
+    }
+}
+
+/// 설정 명령을 실행하고 결과를 표준 출력에 표시
+pub fn run_config(command: &ConfigCommand, args: &CliArgs) -> Result<()> {
+    match command {
+        ConfigCommand::Convert(format) => convert(*format),
+        ConfigCommand::Show { origin } => show(args, *origin),
+        ConfigCommand::Export { path, options } => export(args, path, *options),
+        ConfigCommand::Import { path, yes } => import(args, path, *yes),
+    }
+}
+
+/// 환경 변수와 명령줄 값은 이번 실행에만 쓰는 값이므로 제외하고 파일에 저장된 설정을 내보냄
+fn export(args: &CliArgs, path: &Path, options: ExportOptions) -> Result<()> {
+    let (store, warnings) = ProfileStore::load()?;
+    let (name, settings, profile_warnings) = store.load_selected(args.profile.as_deref())?;
+    for warning in warnings.into_iter().chain(profile_warnings) {
+        eprintln!("경고: {}", warning);
+    }
+
+    let bundle = Bundle::new(&settings, &name, &store, options);
+    bundle.save(path)?;
+    println!("{}", path.display());
+    println!("{}", bundle.describe());
+    Ok(())
+}
+
+/// 바뀌는 내용을 출력하고, `yes`이면 선택한 프로필에 적용
+fn import(args: &CliArgs, path: &Path, yes: bool) -> Result<()> {
+    let bundle = Bundle::load(path)?;
+    let (mut store, warnings) = ProfileStore::load()?;
+    let (name, settings, profile_warnings) = store.load_selected(args.profile.as_deref())?;
+    for warning in warnings.into_iter().chain(profile_warnings) {
+        eprintln!("경고: {}", warning);
+    }
+
+    let preview = bundle.preview(&settings, &store);
+    for warning in &preview.warnings {
+        eprintln!("경고: {}", warning);
+    }
+    println!("# {}", bundle.describe());
+    if preview.is_empty() {
+        println!("바뀌는 내용이 없습니다");
+        return Ok(());
+    }
+    if !preview.changes.is_empty() {
+        println!("[{}] 프로필:", name);
+        for change in &preview.changes {
+            println!("  {}", change);
+        }
+    }
+    for (profile, change) in &preview.profile_changes {
+        println!("프로필 {}: {}", profile, change);
+    }
+    if !preview.commands.is_empty() {
+        println!("주의: 타이머가 만료되면 다음 명령을 셸(sh -c)로 실행합니다:");
+        for (profile, command) in &preview.commands {
+            println!("  [{}] {}", profile.as_deref().unwrap_or(&name), command);
+        }
+    }
+
+    if !yes {
+        println!("적용하려면 --yes를 붙여 다시 실행하세요");
+        return Ok(());
+    }
+    preview.apply(&mut store, &name)?;
+    println!("가져왔습니다");
+    Ok(())
+}
+
+/// 기본값, 설정 파일(프로필), 환경 변수, 명령줄 값을 차례로 적용한 최종 설정을 출력
+fn show(args: &CliArgs, with_origin: bool) -> Result<()> {
+    let (store, mut warnings) = ProfileStore::load()?;
+    let (name, settings, profile_warnings) = store.load_selected(args.profile.as_deref())?;
+    warnings.extend(profile_warnings);
+
+    // 프로필은 모든 항목을 저장하므로 전부 파일에서 정한 값으로 표시
+    let (label, file_keys) = if name == DEFAULT_PROFILE {
+        let path = Settings::config_path()?;
+        let file_keys = if path.exists() {
+            let format = ConfigFormat::from_path(&path).unwrap_or(ConfigFormat::Json);
+            let (value, _) = backup::load_with_recovery(&path, |content| format.parse(content))?;
+            layers::file_keys(&value)
+        } else {
+            Vec::new()
+        };
+        (path.display().to_string(), file_keys)
+    } else {
+        (format!("profiles.json ({})", name), layers::keys())
+    };
+
+    let (overrides, env_warnings) = Overrides::from_env(args.overrides());
+    warnings.extend(env_warnings);
+    let resolved = layers::resolve(&settings, &file_keys, &label, &overrides);
+    warnings.extend(resolved.warnings.iter().cloned());
+
+    for warning in warnings {
+        eprintln!("경고: {}", warning);
+    }
+    if with_origin {
+        println!("# 설정 위치: {}", ConfigLocation::current()?.describe());
+    }
+    for (key, value, origin) in resolved.entries() {
+        if with_origin {
+            println!("{} = {}  # {}", key, value, origin);
+        } else {
+            println!("{} = {}", key, value);
+        }
+    }
+    Ok(())
+}
+
+/// 사용 중인 설정 파일을 지정한 형식으로 다시 저장하고, 기존 파일은 `.bak`을 붙여 남겨 둠
+fn convert(format: ConfigFormat) -> Result<()> {
+    let source = Settings::config_path()?;
+    if !source.exists() {
+        return Err(anyhow::anyhow!(
+            "변환할 설정 파일이 없습니다: {}",
+            source.display()
+        ));
+    }
+    if ConfigFormat::from_path(&source) == Some(format) {
+        return Err(anyhow::anyhow!(
+            "이미 {} 형식입니다: {}",
+            format,
+            source.display()
+        ));
+    }
+
+    let target = Settings::config_path_for(format)?;
+    if target.exists() {
+        return Err(anyhow::anyhow!(
+            "변환할 파일이 이미 있습니다: {}",
+            target.display()
+        ));
+    }
+
+    let (settings, warnings) = Settings::load_from(&source)?;
+    for warning in warnings {
+        eprintln!("경고: {}", warning);
+    }
+    settings.save_to(&target)?;
+
+    // 기존 파일이 남아 있으면 새 파일 대신 읽힐 수 있으므로 이름을 바꿔 둠
+    let mut backup = source.clone().into_os_string();
+    backup.push(".bak");
+    fs::rename(&source, &backup)?;
+
+    println!("{} → {}", source.display(), target.display());
+    println!("기존 파일은 {}에 남겨 두었습니다", backup.to_string_lossy());
+    if ConfigLocation::current()?.explicit_file().is_some() {
+        println!("이후에는 --config {} 로 실행하세요", target.display());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<ConfigCommand> {
+        ConfigCommand::parse(&mut args.iter().map(|arg| arg.to_string()))
+    }
+
+    fn error(args: &[&str]) -> String {
+        parse(args).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn parses_each_subcommand() {
+        assert_eq!(
+            parse(&["convert", "yaml"]).unwrap(),
+            ConfigCommand::Convert(ConfigFormat::Yaml)
+        );
+        assert_eq!(
+            parse(&["show"]).unwrap(),
+            ConfigCommand::Show { origin: false }
+        );
+        assert_eq!(
+            parse(&["show", "--origin"]).unwrap(),
+            ConfigCommand::Show { origin: true }
+        );
+        assert_eq!(
+            parse(&["export", "--profiles", "out.json", "--rules"]).unwrap(),
+            ConfigCommand::Export {
+                path: PathBuf::from("out.json"),
+                options: ExportOptions {
+                    profiles: true,
+                    schedule: false,
+                    rules: true,
+                },
+            }
+        );
+        assert_eq!(
+            parse(&["import", "in.json"]).unwrap(),
+            ConfigCommand::Import {
+                path: PathBuf::from("in.json"),
+                yes: false,
+            }
+        );
+        assert_eq!(
+            parse(&["import", "-y", "in.json"]).unwrap(),
+            ConfigCommand::Import {
+                path: PathBuf::from("in.json"),
+                yes: true,
+            }
+        );
+    }
+
+    #[test]
+    fn rejects_missing_and_unknown_arguments() {
+        assert_eq!(
+            error(&[]),
+            "config 명령이 필요합니다 (convert, show, export, import)"
+        );
+        assert_eq!(error(&["edit"]), "알 수 없는 config 명령: edit");
+        assert_eq!(
+            error(&["convert"]),
+            "config convert 명령에 형식(json, toml, yaml)이 필요합니다"
+        );
+        assert_eq!(error(&["convert", "ini"]), "알 수 없는 설정 형식: ini");
+        assert_eq!(error(&["show", "--all"]), "알 수 없는 인자: --all");
+        assert_eq!(
+            error(&["export", "--all"]),
+            "config export 명령에 내보낼 파일 경로가 필요합니다"
+        );
+        assert_eq!(
+            error(&["export", "a.json", "b.json"]),
+            "알 수 없는 인자: b.json"
+        );
+        assert_eq!(
+            error(&["import"]),
+            "config import 명령에 가져올 파일 경로가 필요합니다"
+        );
+        assert_eq!(
+            error(&["import", "in.json", "--force"]),
+            "알 수 없는 인자: --force"
+        );
+    }
+}
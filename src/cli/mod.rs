@@ -0,0 +1,7 @@
+pub mod args;
+pub mod config;
+pub mod run;
+
+pub use args::{CliArgs, CliCommand, USAGE};
+pub use config::{run_config, ConfigCommand};
+pub use run::run_wrapped;
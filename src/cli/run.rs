@@ -0,0 +1,183 @@
+use crate::config::Settings;
+use crate::mouse::MouseController;
+use anyhow::Result;
+use std::io::ErrorKind;
+use std::process::{Command, ExitStatus};
+
+/// 명령을 실행하는 동안 자리비움 방지 동작을 수행하고 명령의 종료 코드를 반환
+pub fn run_wrapped(program: &[String], settings: Settings) -> Result<i32> {
+    let (command, args) = program
+        .split_first()
+        .ok_or_else(|| anyhow::anyhow!("실행할 명령이 필요합니다"))?;
+
+    // 실행 직후에 받은 시그널도 놓치지 않도록 처리기를 먼저 설치
+    #[cfg(unix)]
+    signals::install();
+
+    let mut child = match Command::new(command).args(args).spawn() {
+        Ok(child) => child,
+        Err(e) => {
+            #[cfg(unix)]
+            signals::restore();
+            eprintln!("명령 실행 실패 ({}): {}", command, e);
+            return Ok(spawn_error_code(&e));
+        }
+    };
+
+    #[cfg(unix)]
+    signals::forward_to(child.id());
+
+    let mut controller = MouseController::new();
+    controller.start(settings);
+    log::info!("명령 실행 중 자리비움 방지 시작: {}", program.join(" "));
+
+    let status = child.wait();
+
+    controller.stop();
+    #[cfg(unix)]
+    signals::restore();
+
+    Ok(exit_code(status?))
+}
+
+/// 셸과 같은 규칙으로 실행 실패 종료 코드 반환 (명령 없음 127, 실행 불가 126)
+fn spawn_error_code(error: &std::io::Error) -> i32 {
+    if error.kind() == ErrorKind::NotFound {
+        127
+    } else {
+        126
+    }
+}
+
+fn exit_code(status: ExitStatus) -> i32 {
+    if let Some(code) = status.code() {
+        return code;
+    }
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::ExitStatusExt;
+
+        // 시그널로 종료된 경우 셸과 같이 128 + 시그널 번호
+        if let Some(signal) = status.signal() {
+            return 128 + signal;
+        }
+    }
+
+    1
+}
+
+#[cfg(unix)]
+mod signals {
+    use std::sync::atomic::{AtomicI32, Ordering};
+
+    static CHILD_PID: AtomicI32 = AtomicI32::new(0);
+    // 자식이 실행되기 전에 받은 시그널 (0이면 없음)
+    static PENDING_SIGNAL: AtomicI32 = AtomicI32::new(0);
+
+    const FORWARDED: [libc::c_int; 6] = [
+        libc::SIGINT,
+        libc::SIGTERM,
+        libc::SIGHUP,
+        libc::SIGQUIT,
+        libc::SIGUSR1,
+        libc::SIGUSR2,
+    ];
+
+    /// 시그널 처리기 설치 (자식이 실행되기 전에 받은 시그널은 `forward_to`에서 전달)
+    pub fn install() {
+        CHILD_PID.store(0, Ordering::SeqCst);
+        PENDING_SIGNAL.store(0, Ordering::SeqCst);
+
+        unsafe {
+            let mut action: libc::sigaction = std::mem::zeroed();
+            action.sa_sigaction = handle_signal as *const () as libc::sighandler_t;
+            action.sa_flags = libc::SA_SIGINFO | libc::SA_RESTART;
+            libc::sigemptyset(&mut action.sa_mask);
+
+            for signal in FORWARDED {
+                libc::sigaction(signal, &action, std::ptr::null_mut());
+            }
+        }
+    }
+
+    /// 이후 받은 시그널을 지정한 자식 프로세스로 전달
+    pub fn forward_to(pid: u32) {
+        CHILD_PID.store(pid as i32, Ordering::SeqCst);
+
+        let pending = PENDING_SIGNAL.swap(0, Ordering::SeqCst);
+        if pending != 0 {
+            unsafe {
+                libc::kill(pid as i32, pending);
+            }
+        }
+    }
+
+    /// 전달을 멈추고 기본 처리기로 되돌림
+    pub fn restore() {
+        unsafe {
+            for signal in FORWARDED {
+                libc::signal(signal, libc::SIG_DFL);
+            }
+        }
+        CHILD_PID.store(0, Ordering::SeqCst);
+        PENDING_SIGNAL.store(0, Ordering::SeqCst);
+    }
+
+    extern "C" fn handle_signal(
+        signal: libc::c_int,
+        info: *mut libc::siginfo_t,
+        _context: *mut libc::c_void,
+    ) {
+        // 터미널에서 발생한 시그널(Ctrl+C 등)은 자식도 이미 받았으므로 다시 보내지 않음
+        let from_terminal = !info.is_null() && unsafe { (*info).si_code } == libc::SI_KERNEL;
+        let pid = CHILD_PID.load(Ordering::SeqCst);
+
+        if pid == 0 {
+            // 아직 실행되지 않은 자식은 터미널 시그널도 받지 못했으므로 기억해 두었다가 전달
+            PENDING_SIGNAL.store(signal, Ordering::SeqCst);
+        } else if !from_terminal {
+            unsafe {
+                libc::kill(pid, signal);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(program: &[&str]) -> i32 {
+        let program: Vec<String> = program.iter().map(|arg| arg.to_string()).collect();
+        run_wrapped(&program, Settings::default()).unwrap()
+    }
+
+    #[test]
+    fn missing_command_exits_127() {
+        assert_eq!(run(&["auto-mouse-no-such-command"]), 127);
+    }
+
+    #[test]
+    fn non_executable_command_exits_126() {
+        // 디렉토리는 실행할 수 없으므로 권한 오류
+        let dir = std::env::temp_dir();
+        assert_eq!(run(&[dir.to_str().unwrap()]), 126);
+    }
+
+    #[test]
+    fn spawn_errors_map_like_a_shell() {
+        let not_found = std::io::Error::from(ErrorKind::NotFound);
+        let denied = std::io::Error::from(ErrorKind::PermissionDenied);
+        assert_eq!(spawn_error_code(&not_found), 127);
+        assert_eq!(spawn_error_code(&denied), 126);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn exit_code_follows_child_status() {
+        let status = |script: &str| Command::new("sh").args(["-c", script]).status().unwrap();
+        assert_eq!(exit_code(status("exit 3")), 3);
+        assert_eq!(exit_code(status("kill -TERM $$")), 128 + libc::SIGTERM);
+    }
+}
@@ -0,0 +1,283 @@
+use anyhow::Result;
+use chrono::Local;
+use std::fs::{self, File};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// 백업을 저장하는 하위 디렉토리 (설정 파일 감시 대상에서 벗어나도록 분리)
+const BACKUP_DIR: &str = "backups";
+
+/// 파일마다 유지할 백업 개수
+pub const BACKUP_COUNT: usize = 5;
+
+/// 임시 파일에 쓰고 디스크에 기록한 뒤 이름을 바꿔, 쓰는 도중 종료되어도 기존 파일이 온전히 남도록 함
+///
+/// 심볼릭 링크(dotfile 관리 도구 등)는 링크를 유지하고 가리키는 파일을 교체하며, 기존 파일의 권한을 그대로 이어받음
+pub fn write_atomic(path: &Path, content: &[u8]) -> Result<()> {
+    let target = resolve_link(path);
+    let path = target.as_path();
+    let dir = parent_dir(path);
+    fs::create_dir_all(dir)?;
+    let temp = dir.join(format!(".{}.tmp-{}", file_name(path)?, std::process::id()));
+    let permissions = fs::metadata(path).ok().map(|meta| meta.permissions());
+
+    let result = (|| -> Result<()> {
+        let mut file = File::create(&temp)?;
+        file.write_all(content)?;
+        if let Some(permissions) = permissions {
+            file.set_permissions(permissions)?;
+        }
+        file.sync_all()?;
+        fs::rename(&temp, path)?;
+        sync_dir(dir);
+        Ok(())
+    })();
+
+    if result.is_err() {
+        let _ = fs::remove_file(&temp);
+    }
+    result
+}
+
+/// 기존 내용을 백업한 뒤 새 내용으로 교체 (백업은 최근 `BACKUP_COUNT`개만 유지)
+pub fn write_with_backup(path: &Path, content: &str) -> Result<()> {
+    if let Ok(previous) = fs::read(path) {
+        if previous != content.as_bytes() {
+            // 백업에 실패해도 저장은 계속 진행
+            if let Err(e) = backup(path, &previous) {
+                log::warn!("설정 백업 실패 ({}): {}", path.display(), e);
+            }
+        }
+    }
+    write_atomic(path, content.as_bytes())
+}
+
+/// 파일을 읽어 해석하고, 해석할 수 없으면 가장 최근의 올바른 백업으로 대체
+///
+/// 백업에서 복구한 경우 사용자에게 보여 줄 안내를 함께 반환
+pub fn load_with_recovery<T, F>(path: &Path, parse: F) -> Result<(T, Option<String>)>
+where
+    F: Fn(&str) -> Result<T>,
+{
+    let error = match parse(&fs::read_to_string(path)?) {
+        Ok(value) => return Ok((value, None)),
+        Err(e) => e,
+    };
+
+    for backup in backups(path) {
+        let Ok(content) = fs::read_to_string(&backup) else {
+            continue;
+        };
+        if let Ok(value) = parse(&content) {
+            let notice = format!(
+                "{}을(를) 읽을 수 없어({}) 백업 {}에서 복구했습니다. 저장하면 복구한 내용으로 덮어씁니다",
+                path.display(),
+                error,
+                backup.display()
+            );
+            log::warn!("{}", notice);
+            return Ok((value, Some(notice)));
+        }
+    }
+    Err(error)
+}
+
+/// 파일의 백업 목록 (최근 것부터)
+pub fn backups(path: &Path) -> Vec<PathBuf> {
+    let Ok(prefix) = file_name(path).map(|name| format!("{}.", name)) else {
+        return Vec::new();
+    };
+    let Ok(entries) = fs::read_dir(backup_dir(path)) else {
+        return Vec::new();
+    };
+
+    let mut backups: Vec<PathBuf> = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|backup| {
+            backup
+                .file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with(&prefix))
+        })
+        .collect();
+    backups.sort();
+    backups.reverse();
+    backups
+}
+
+fn backup(path: &Path, content: &[u8]) -> Result<()> {
+    let dir = backup_dir(path);
+    fs::create_dir_all(&dir)?;
+
+    // 이름순이 시간순이 되도록 시각을 붙이고, 같은 시각이면 번호를 더함
+    let stem = format!(
+        "{}.{}",
+        file_name(path)?,
+        Local::now().format("%Y%m%d-%H%M%S%.3f")
+    );
+    let mut target = dir.join(&stem);
+    let mut index = 1;
+    while target.exists() {
+        target = dir.join(format!("{}-{}", stem, index));
+        index += 1;
+    }
+    write_atomic(&target, content)?;
+
+    for old in backups(path).into_iter().skip(BACKUP_COUNT) {
+        fs::remove_file(old)?;
+    }
+    Ok(())
+}
+
+/// 심볼릭 링크면 실제로 가리키는 파일 경로 (대상이 아직 없는 링크도 링크 내용을 따라감)
+fn resolve_link(path: &Path) -> PathBuf {
+    if let Ok(real) = fs::canonicalize(path) {
+        return real;
+    }
+    match fs::read_link(path) {
+        Ok(link) => parent_dir(path).join(link),
+        Err(_) => path.to_path_buf(),
+    }
+}
+
+fn backup_dir(path: &Path) -> PathBuf {
+    parent_dir(path).join(BACKUP_DIR)
+}
+
+fn parent_dir(path: &Path) -> &Path {
+    match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    }
+}
+
+fn file_name(path: &Path) -> Result<String> {
+    path.file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .ok_or_else(|| anyhow::anyhow!("파일 이름이 없습니다: {}", path.display()))
+}
+
+/// 이름 바꾸기가 디스크에 기록되도록 디렉토리도 동기화
+fn sync_dir(dir: &Path) {
+    #[cfg(unix)]
+    {
+        if let Ok(dir) = File::open(dir) {
+            let _ = dir.sync_all();
+        }
+    }
+
+    #[cfg(not(unix))]
+    {
+        let _ = dir;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::temp_dir;
+
+    fn parse_number(content: &str) -> Result<u32> {
+        Ok(content.trim().parse()?)
+    }
+
+    #[test]
+    fn keeps_only_recent_backups() {
+        let dir = temp_dir("backup-rotate");
+        let path = dir.join("config.json");
+
+        for i in 0..BACKUP_COUNT + 3 {
+            write_with_backup(&path, &i.to_string()).unwrap();
+        }
+        // 같은 내용은 백업하지 않음
+        write_with_backup(&path, &(BACKUP_COUNT + 2).to_string()).unwrap();
+
+        let backups = backups(&path);
+        assert_eq!(backups.len(), BACKUP_COUNT);
+        assert_eq!(
+            fs::read_to_string(&backups[0]).unwrap(),
+            (BACKUP_COUNT + 1).to_string()
+        );
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            (BACKUP_COUNT + 2).to_string()
+        );
+        // 임시 파일이 남지 않음
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 2);
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn recovers_from_newest_valid_backup() {
+        let dir = temp_dir("backup-recover");
+        let path = dir.join("config.json");
+
+        write_with_backup(&path, "1").unwrap();
+        write_with_backup(&path, "2").unwrap();
+        write_with_backup(&path, "망가진 내용").unwrap();
+        // 쓰다가 잘린 파일
+        fs::write(&path, "").unwrap();
+
+        let (value, notice) = load_with_recovery(&path, parse_number).unwrap();
+        assert_eq!(value, 2);
+        assert!(notice.is_some());
+
+        write_atomic(&path, b"7").unwrap();
+        let (value, notice) = load_with_recovery(&path, parse_number).unwrap();
+        assert_eq!(value, 7);
+        assert!(notice.is_none());
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn writes_through_symlink_and_keeps_permissions() {
+        use std::os::unix::fs::{symlink, PermissionsExt};
+
+        let dir = temp_dir("backup-symlink");
+        let real = dir.join("dotfiles").join("config.json");
+        let link = dir.join("config.json");
+        fs::create_dir_all(real.parent().unwrap()).unwrap();
+        fs::write(&real, "1").unwrap();
+        fs::set_permissions(&real, fs::Permissions::from_mode(0o600)).unwrap();
+        symlink(&real, &link).unwrap();
+
+        write_atomic(&link, b"2").unwrap();
+
+        assert!(fs::symlink_metadata(&link)
+            .unwrap()
+            .file_type()
+            .is_symlink());
+        assert_eq!(fs::read_to_string(&real).unwrap(), "2");
+        let mode = fs::metadata(&real).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+        // 임시 파일은 실제 파일 옆에 만들고 남기지 않음
+        assert_eq!(fs::read_dir(real.parent().unwrap()).unwrap().count(), 1);
+
+        // 대상이 아직 없는 링크도 링크를 유지
+        let dangling = dir.join("profiles.json");
+        symlink(dir.join("dotfiles").join("profiles.json"), &dangling).unwrap();
+        write_atomic(&dangling, b"{}").unwrap();
+        assert!(fs::symlink_metadata(&dangling)
+            .unwrap()
+            .file_type()
+            .is_symlink());
+        assert_eq!(fs::read_to_string(&dangling).unwrap(), "{}");
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn fails_when_no_backup_is_valid() {
+        let dir = temp_dir("backup-unrecoverable");
+        let path = dir.join("config.json");
+        fs::write(&path, "x").unwrap();
+
+        assert!(load_with_recovery(&path, parse_number).is_err());
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
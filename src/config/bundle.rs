@@ -0,0 +1,622 @@
+use super::{backup, layers, migrate, ProfileStore, Settings, DEFAULT_PROFILE};
+use crate::timer::ExpiryAction;
+use anyhow::Result;
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+/// 내보낸 파일임을 나타내는 `format` 값
+pub const BUNDLE_FORMAT: &str = "auto-mouse-bundle";
+
+/// 내보내기 파일 형식 버전
+const BUNDLE_VERSION: u32 = 1;
+
+// 선택해서 내보내는 항목 (나머지는 기본 설정으로 항상 포함)
+const SCHEDULE_KEYS: [&str; 2] = ["schedule", "cron"];
+const RULE_KEYS: [&str; 4] = [
+    "process_watch",
+    "resource_trigger",
+    "power",
+    "expiry_actions",
+];
+
+/// 내보낼 때 함께 포함할 항목
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ExportOptions {
+    /// 기본 프로필을 제외한 모든 프로필
+    pub profiles: bool,
+    /// 요일별 시간표와 cron 트리거
+    pub schedule: bool,
+    /// 프로세스 감시, 부하 트리거, 전원, 만료 시 동작
+    pub rules: bool,
+}
+
+impl ExportOptions {
+    pub fn all() -> Self {
+        Self {
+            profiles: true,
+            schedule: true,
+            rules: true,
+        }
+    }
+}
+
+/// 내보낸 파일에 들어 있는 항목
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Section {
+    Settings,
+    Schedule,
+    Rules,
+    Profiles,
+}
+
+impl Section {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Section::Settings => "기본 설정",
+            Section::Schedule => "시간표",
+            Section::Rules => "규칙",
+            Section::Profiles => "프로필",
+        }
+    }
+}
+
+/// 다른 사람과 공유하기 위해 설정(과 선택한 항목)을 담은 파일
+///
+/// 어떤 프로그램이 언제 무엇을 담아 만들었는지 함께 기록하여 파일만 보고도 내용을 알 수 있음
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Bundle {
+    pub format: String,
+    pub bundle_version: u32,
+    pub app_version: String,
+    pub exported_at: DateTime<Local>,
+    /// 설정을 내보낸 프로필
+    pub source_profile: String,
+    pub sections: Vec<Section>,
+    pub settings: Map<String, Value>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub profiles: BTreeMap<String, Map<String, Value>>,
+}
+
+impl Bundle {
+    /// 현재 설정과 선택한 항목으로 내보낼 내용을 만듦
+    pub fn new(
+        settings: &Settings,
+        profile_name: &str,
+        store: &ProfileStore,
+        options: ExportOptions,
+    ) -> Self {
+        let mut sections = vec![Section::Settings];
+        if options.schedule {
+            sections.push(Section::Schedule);
+        }
+        if options.rules {
+            sections.push(Section::Rules);
+        }
+
+        let profiles = if options.profiles {
+            sections.push(Section::Profiles);
+            store
+                .named_profiles()
+                .iter()
+                .map(|(name, settings)| (name.clone(), export_settings(settings, options)))
+                .collect()
+        } else {
+            BTreeMap::new()
+        };
+
+        Self {
+            format: BUNDLE_FORMAT.to_string(),
+            bundle_version: BUNDLE_VERSION,
+            app_version: env!("CARGO_PKG_VERSION").to_string(),
+            exported_at: Local::now(),
+            source_profile: profile_name.to_string(),
+            sections,
+            settings: export_settings(settings, options),
+            profiles,
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        backup::write_atomic(path, serde_json::to_string_pretty(self)?.as_bytes())
+    }
+
+    pub fn load(path: &Path) -> Result<Self> {
+        Self::from_value(serde_json::from_str(&fs::read_to_string(path)?)?)
+    }
+
+    /// 내보내기 파일인지, 읽을 수 있는 버전인지 확인한 뒤 해석
+    pub fn from_value(value: Value) -> Result<Self> {
+        if value.get("format").and_then(Value::as_str) != Some(BUNDLE_FORMAT) {
+            return Err(anyhow::anyhow!("auto-mouse 내보내기 파일이 아닙니다"));
+        }
+        let bundle: Bundle = serde_json::from_value(value)?;
+        if bundle.bundle_version > BUNDLE_VERSION {
+            return Err(anyhow::anyhow!(
+                "더 새로운 버전(v{})에서 내보낸 파일입니다",
+                bundle.bundle_version
+            ));
+        }
+        Ok(bundle)
+    }
+
+    /// 가져오면 바뀌는 내용을 계산 (아직 저장하지 않음)
+    ///
+    /// 파일에 없는 항목(내보낼 때 선택하지 않은 시간표 등)은 현재 값을 유지
+    pub fn preview(&self, current: &Settings, store: &ProfileStore) -> ImportPreview {
+        let mut warnings = Vec::new();
+
+        let settings = overlay(current, &self.settings, &self.sections, &mut warnings);
+        let changes = diff(current, &settings);
+        let mut commands: Vec<(Option<String>, String)> = self
+            .imported_commands(&self.settings, &settings)
+            .into_iter()
+            .map(|command| (None, command))
+            .collect();
+
+        let mut profiles = BTreeMap::new();
+        let mut profile_changes = Vec::new();
+        for (name, value) in &self.profiles {
+            if name == DEFAULT_PROFILE {
+                warnings.push(format!("기본 프로필은 가져오지 않습니다: {}", name));
+                continue;
+            }
+
+            let mut profile_warnings = Vec::new();
+            let (settings, change) = match store.named_profiles().get(name) {
+                Some(existing) => {
+                    let settings = overlay(existing, value, &self.sections, &mut profile_warnings);
+                    let count = diff(existing, &settings).len();
+                    let change = if count == 0 {
+                        ProfileChange::Unchanged
+                    } else {
+                        ProfileChange::Changed(count)
+                    };
+                    (settings, change)
+                }
+                None => (
+                    overlay(
+                        &Settings::default(),
+                        value,
+                        &self.sections,
+                        &mut profile_warnings,
+                    ),
+                    ProfileChange::Added,
+                ),
+            };
+            warnings.extend(
+                profile_warnings
+                    .into_iter()
+                    .map(|w| format!("[{}] {}", name, w)),
+            );
+            commands.extend(
+                self.imported_commands(value, &settings)
+                    .into_iter()
+                    .map(|command| (Some(name.clone()), command)),
+            );
+            if change != ProfileChange::Unchanged {
+                profiles.insert(name.clone(), settings);
+            }
+            profile_changes.push((name.clone(), change));
+        }
+
+        ImportPreview {
+            settings,
+            changes,
+            profiles,
+            profile_changes,
+            commands,
+            warnings,
+        }
+    }
+
+    /// 파일에서 가져오는 만료 시 동작 중 셸로 실행되는 명령
+    fn imported_commands(&self, value: &Map<String, Value>, settings: &Settings) -> Vec<String> {
+        if !self.sections.contains(&Section::Rules) || !value.contains_key("expiry_actions") {
+            return Vec::new();
+        }
+        settings
+            .expiry_actions
+            .iter()
+            .filter_map(|action| match action {
+                ExpiryAction::RunCommand { command } => Some(command.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// 내보낸 시각과 담긴 항목 요약
+    pub fn describe(&self) -> String {
+        let sections: Vec<String> = self
+            .sections
+            .iter()
+            .map(|section| match section {
+                Section::Profiles => format!("{} {}개", section.label(), self.profiles.len()),
+                _ => section.label().to_string(),
+            })
+            .collect();
+        format!(
+            "{}에 '{}' 프로필에서 내보냄 (v{}): {}",
+            self.exported_at.format("%Y-%m-%d %H:%M"),
+            self.source_profile,
+            self.app_version,
+            sections.join(", ")
+        )
+    }
+}
+
+/// 설정 항목 하나의 변경 내용
+#[derive(Debug, Clone, PartialEq)]
+pub struct Change {
+    pub key: String,
+    pub before: Value,
+    pub after: Value,
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {} → {}", self.key, self.before, self.after)
+    }
+}
+
+/// 가져올 프로필의 변경 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProfileChange {
+    Added,
+    /// 바뀌는 항목 수
+    Changed(usize),
+    Unchanged,
+}
+
+impl fmt::Display for ProfileChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProfileChange::Added => write!(f, "추가"),
+            ProfileChange::Changed(count) => write!(f, "{}개 항목 변경", count),
+            ProfileChange::Unchanged => write!(f, "변경 없음"),
+        }
+    }
+}
+
+/// 가져오기 전에 보여 줄 변경 내용과 가져온 결과
+#[derive(Debug, Clone)]
+pub struct ImportPreview {
+    /// 가져온 뒤의 설정
+    pub settings: Settings,
+    pub changes: Vec<Change>,
+    /// 추가하거나 바뀌는 프로필
+    pub profiles: BTreeMap<String, Settings>,
+    pub profile_changes: Vec<(String, ProfileChange)>,
+    /// 타이머가 만료되면 셸(`sh -c`)로 실행할, 파일에 담긴 명령과 그 프로필 (None이면 가져올 프로필)
+    pub commands: Vec<(Option<String>, String)>,
+    pub warnings: Vec<String>,
+}
+
+impl ImportPreview {
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty() && self.profiles.is_empty()
+    }
+
+    /// 가져온 설정을 지정한 프로필에 저장하고 프로필을 추가하거나 덮어씀
+    ///
+    /// 일부만 적용되지 않도록 저장할 설정을 모두 확인한 뒤에 파일에 씀
+    pub fn apply(&self, store: &mut ProfileStore, profile_name: &str) -> Result<()> {
+        store.check_save(profile_name, &self.settings)?;
+        if !self.profiles.is_empty() {
+            store.import(&self.profiles)?;
+        }
+        store.save_profile(profile_name, &self.settings)
+    }
+}
+
+/// 두 설정 사이에 값이 다른 항목
+pub fn diff(before: &Settings, after: &Settings) -> Vec<Change> {
+    let before = layers::flatten(before);
+    let after = layers::flatten(after);
+    after
+        .into_iter()
+        .filter_map(|(key, after)| {
+            let before = before.get(&key).cloned().unwrap_or(Value::Null);
+            (before != after).then_some(Change { key, before, after })
+        })
+        .collect()
+}
+
+/// 선택하지 않은 항목을 뺀 설정 값
+fn export_settings(settings: &Settings, options: ExportOptions) -> Map<String, Value> {
+    let Ok(Value::Object(mut map)) = serde_json::to_value(settings) else {
+        return Map::new();
+    };
+    if !options.schedule {
+        for key in SCHEDULE_KEYS {
+            map.remove(key);
+        }
+    }
+    if !options.rules {
+        for key in RULE_KEYS {
+            map.remove(key);
+        }
+    }
+    map
+}
+
+/// 파일에 담긴 항목만 `base` 위에 덮어씀 (이전 버전에서 내보낸 값은 현재 형식으로 변환)
+///
+/// 객체는 항목별로 합치므로 일부 값만 담긴 파일도 나머지는 현재 값을 유지
+fn overlay(
+    base: &Settings,
+    value: &Map<String, Value>,
+    sections: &[Section],
+    warnings: &mut Vec<String>,
+) -> Settings {
+    let mut present = value.clone();
+    let imported = match migrate::upgrade(&mut present).and_then(|upgrade_warnings| {
+        warnings.extend(upgrade_warnings);
+        migrate::parse_value(Value::Object(present.clone()))
+    }) {
+        Ok((settings, parse_warnings)) => {
+            warnings.extend(parse_warnings);
+            settings
+        }
+        Err(e) => {
+            warnings.push(format!("설정을 읽을 수 없어 가져오지 않습니다: {}", e));
+            return base.clone();
+        }
+    };
+
+    let (Ok(Value::Object(mut merged)), Ok(Value::Object(imported))) =
+        (serde_json::to_value(base), serde_json::to_value(&imported))
+    else {
+        return base.clone();
+    };
+    let mut skipped = vec!["version"];
+    if !sections.contains(&Section::Schedule) {
+        skipped.extend(SCHEDULE_KEYS);
+    }
+    if !sections.contains(&Section::Rules) {
+        skipped.extend(RULE_KEYS);
+    }
+    for key in skipped {
+        present.remove(key);
+    }
+    merge_present(&mut merged, &present, &imported);
+    serde_json::from_value(Value::Object(merged)).unwrap_or_else(|_| base.clone())
+}
+
+/// `present`에 있는 항목만 해석된 값(`imported`)으로 바꿈 (배열과 값은 통째로 교체)
+fn merge_present(
+    target: &mut Map<String, Value>,
+    present: &Map<String, Value>,
+    imported: &Map<String, Value>,
+) {
+    for (key, present) in present {
+        let Some(imported) = imported.get(key) else {
+            continue;
+        };
+        match (present, imported, target.get_mut(key)) {
+            (Value::Object(present), Value::Object(imported), Some(Value::Object(target))) => {
+                merge_present(target, present, imported)
+            }
+            _ => {
+                target.insert(key.clone(), imported.clone());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::TimeRange;
+    use chrono::NaiveTime;
+
+    fn tuned() -> Settings {
+        let mut settings = Settings {
+            interval_seconds: 45.0,
+            ..Settings::default()
+        };
+        settings.process_watch.enabled = true;
+        settings.process_watch.target = "ffmpeg*".to_string();
+        settings.schedule.days[0].push(TimeRange::new(
+            NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+            NaiveTime::from_hms_opt(12, 0, 0).unwrap(),
+        ));
+        settings
+    }
+
+    fn round_trip(bundle: &Bundle) -> Bundle {
+        Bundle::from_value(serde_json::to_value(bundle).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn unselected_sections_keep_current_values() {
+        let bundle = round_trip(&Bundle::new(
+            &tuned(),
+            DEFAULT_PROFILE,
+            &ProfileStore::default(),
+            ExportOptions::default(),
+        ));
+        assert_eq!(bundle.sections, vec![Section::Settings]);
+        assert!(!bundle.settings.contains_key("schedule"));
+        assert!(!bundle.settings.contains_key("process_watch"));
+
+        let mut current = Settings::default();
+        current.process_watch.target = "현재 값".to_string();
+        let preview = bundle.preview(&current, &ProfileStore::default());
+
+        assert!(preview.warnings.is_empty());
+        assert_eq!(preview.settings.interval_seconds, 45.0);
+        assert_eq!(preview.settings.process_watch.target, "현재 값");
+        assert_eq!(
+            preview.changes,
+            vec![Change {
+                key: "interval_seconds".to_string(),
+                before: 30.0.into(),
+                after: 45.0.into(),
+            }]
+        );
+    }
+
+    #[test]
+    fn full_export_includes_rules_schedule_and_profiles() {
+        let mut bundle = Bundle::new(
+            &tuned(),
+            DEFAULT_PROFILE,
+            &ProfileStore::default(),
+            ExportOptions::all(),
+        );
+        bundle.profiles.insert(
+            "야간".to_string(),
+            export_settings(&tuned(), ExportOptions::all()),
+        );
+        let bundle = round_trip(&bundle);
+        assert!(bundle.describe().contains("프로필 1개"));
+
+        let preview = bundle.preview(&Settings::default(), &ProfileStore::default());
+        let keys: Vec<&str> = preview.changes.iter().map(|c| c.key.as_str()).collect();
+        assert_eq!(
+            keys,
+            vec![
+                "interval_seconds",
+                "process_watch.enabled",
+                "process_watch.target",
+                "schedule.days",
+            ]
+        );
+        assert_eq!(
+            preview.profile_changes,
+            vec![("야간".to_string(), ProfileChange::Added)]
+        );
+
+        // 설정이 같아도 새 프로필이 있으면 가져올 내용이 있음
+        let preview = bundle.preview(&tuned(), &ProfileStore::default());
+        assert!(preview.changes.is_empty());
+        assert!(!preview.is_empty());
+    }
+
+    #[test]
+    fn partial_bundle_keeps_missing_values() {
+        let mut bundle = Bundle::new(
+            &Settings::default(),
+            DEFAULT_PROFILE,
+            &ProfileStore::default(),
+            ExportOptions::all(),
+        );
+        // 손으로 줄인 파일: 전원 설정 중 하나와 간격만 담음
+        bundle.settings = serde_json::json!({
+            "interval_seconds": 50.0,
+            "power": {"pause_on_battery": true},
+        })
+        .as_object()
+        .unwrap()
+        .clone();
+        let bundle = round_trip(&bundle);
+
+        let mut current = tuned();
+        current.power.pause_below_percent = 42;
+        let preview = bundle.preview(&current, &ProfileStore::default());
+
+        assert!(preview.warnings.is_empty());
+        assert_eq!(preview.settings.interval_seconds, 50.0);
+        assert!(preview.settings.power.pause_on_battery);
+        assert_eq!(preview.settings.power.pause_below_percent, 42);
+        assert_eq!(preview.settings.process_watch.target, "ffmpeg*");
+        assert_eq!(preview.settings.schedule.days, current.schedule.days);
+        // 파일에 없는 항목은 기본값으로 돌아가지 않음
+        let keys: Vec<&str> = preview.changes.iter().map(|c| c.key.as_str()).collect();
+        assert_eq!(keys, vec!["interval_seconds", "power.pause_on_battery"]);
+    }
+
+    #[test]
+    fn lists_imported_shell_commands() {
+        let mut shared = tuned();
+        shared.expiry_actions = vec![
+            ExpiryAction::Notify {
+                message: "끝".to_string(),
+            },
+            ExpiryAction::RunCommand {
+                command: "rm -rf ~/tmp".to_string(),
+            },
+        ];
+        let mut bundle = Bundle::new(
+            &shared,
+            DEFAULT_PROFILE,
+            &ProfileStore::default(),
+            ExportOptions::all(),
+        );
+        bundle.profiles.insert(
+            "야간".to_string(),
+            export_settings(&shared, ExportOptions::all()),
+        );
+        let bundle = round_trip(&bundle);
+
+        let preview = bundle.preview(&Settings::default(), &ProfileStore::default());
+        assert_eq!(
+            preview.commands,
+            vec![
+                (None, "rm -rf ~/tmp".to_string()),
+                (Some("야간".to_string()), "rm -rf ~/tmp".to_string()),
+            ]
+        );
+
+        // 규칙을 담지 않은 파일은 명령을 가져오지 않음
+        let bundle = round_trip(&Bundle::new(
+            &shared,
+            DEFAULT_PROFILE,
+            &ProfileStore::default(),
+            ExportOptions::default(),
+        ));
+        let current = Settings {
+            expiry_actions: shared.expiry_actions.clone(),
+            ..Settings::default()
+        };
+        assert!(bundle
+            .preview(&current, &ProfileStore::default())
+            .commands
+            .is_empty());
+    }
+
+    #[test]
+    fn invalid_settings_leave_profiles_untouched() {
+        let path = crate::test_util::temp_dir("bundle-apply").join("profiles.json");
+        let (mut store, _) = ProfileStore::load_from(&path).unwrap();
+        store.create("야간", Settings::default()).unwrap();
+
+        let preview = ImportPreview {
+            settings: Settings {
+                interval_seconds: 0.0,
+                ..Settings::default()
+            },
+            changes: Vec::new(),
+            profiles: BTreeMap::from([("새 프로필".to_string(), tuned())]),
+            profile_changes: Vec::new(),
+            commands: Vec::new(),
+            warnings: Vec::new(),
+        };
+        assert!(preview.apply(&mut store, "야간").is_err());
+        assert!(preview.apply(&mut store, "없는 프로필").is_err());
+
+        let (store, _) = ProfileStore::load_from(&path).unwrap();
+        assert!(!store.contains("새 프로필"));
+    }
+
+    #[test]
+    fn rejects_other_files() {
+        assert!(Bundle::from_value(serde_json::json!({"interval_seconds": 10})).is_err());
+
+        let mut value = serde_json::to_value(Bundle::new(
+            &Settings::default(),
+            DEFAULT_PROFILE,
+            &ProfileStore::default(),
+            ExportOptions::default(),
+        ))
+        .unwrap();
+        value["bundle_version"] = (BUNDLE_VERSION + 1).into();
+        assert!(Bundle::from_value(value).is_err());
+    }
+}
@@ -0,0 +1,653 @@
+use crate::timer::WallClock;
+use chrono::{DateTime, Datelike, Local, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Timelike};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// 다음/이전 실행 시각을 찾을 때 살펴볼 최대 일수 (약 5년)
+const MAX_SEARCH_DAYS: u32 = 5 * 366;
+
+const MONTH_NAMES: [&str; 12] = [
+    "JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC",
+];
+const WEEKDAY_NAMES: [&str; 7] = ["SUN", "MON", "TUE", "WED", "THU", "FRI", "SAT"];
+
+/// 설정에 저장되는 시작/중지 cron 표현식 쌍
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CronTrigger {
+    pub start: String,
+    pub stop: String,
+}
+
+impl Default for CronTrigger {
+    fn default() -> Self {
+        // 매월 첫 번째 월요일 08:00 ~ 12:00
+        Self {
+            start: "0 8 * * MON#1".to_string(),
+            stop: "0 12 * * MON#1".to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CronSettings {
+    pub enabled: bool,
+    pub triggers: Vec<CronTrigger>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CronError {
+    pub field: &'static str,
+    pub message: String,
+}
+
+impl CronError {
+    fn new(field: &'static str, message: impl Into<String>) -> Self {
+        Self {
+            field,
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for CronError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.field.is_empty() {
+            write!(f, "{}", self.message)
+        } else {
+            write!(f, "{} 필드: {}", self.field, self.message)
+        }
+    }
+}
+
+impl std::error::Error for CronError {}
+
+struct FieldSpec {
+    name: &'static str,
+    min: u32,
+    max: u32,
+    names: &'static [&'static str],
+}
+
+const MINUTE: FieldSpec = FieldSpec {
+    name: "분",
+    min: 0,
+    max: 59,
+    names: &[],
+};
+const HOUR: FieldSpec = FieldSpec {
+    name: "시",
+    min: 0,
+    max: 23,
+    names: &[],
+};
+const DAY_OF_MONTH: FieldSpec = FieldSpec {
+    name: "일",
+    min: 1,
+    max: 31,
+    names: &[],
+};
+const MONTH: FieldSpec = FieldSpec {
+    name: "월",
+    min: 1,
+    max: 12,
+    names: &MONTH_NAMES,
+};
+const DAY_OF_WEEK: FieldSpec = FieldSpec {
+    name: "요일",
+    min: 0,
+    max: 7,
+    names: &WEEKDAY_NAMES,
+};
+
+impl FieldSpec {
+    fn value(&self, text: &str) -> Result<u32, CronError> {
+        let upper = text.to_ascii_uppercase();
+        if let Some(index) = self.names.iter().position(|name| *name == upper) {
+            return Ok(index as u32 + self.min);
+        }
+
+        let value: u32 = text.parse().map_err(|_| {
+            CronError::new(self.name, format!("'{}'은(는) 올바른 값이 아닙니다", text))
+        })?;
+        if value < self.min || value > self.max {
+            return Err(CronError::new(
+                self.name,
+                format!(
+                    "{}은(는) 허용 범위({}-{})를 벗어났습니다",
+                    value, self.min, self.max
+                ),
+            ));
+        }
+        Ok(value)
+    }
+}
+
+/// 분 시 일 월 요일 형식의 cron 표현식
+///
+/// `*`, 목록(`,`), 범위(`-`), 간격(`/`), 월/요일 이름, 그리고 요일 필드의
+/// `요일#n`(그 달의 n번째 요일, 예: `MON#1`)을 지원함
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CronExpr {
+    minutes: u64,
+    hours: u64,
+    days: u64,
+    months: u64,
+    weekdays: u64,
+    // (요일, n번째)
+    nth_weekdays: Vec<(u32, u32)>,
+    day_restricted: bool,
+    weekday_restricted: bool,
+}
+
+impl CronExpr {
+    pub fn parse(expression: &str) -> Result<Self, CronError> {
+        let fields: Vec<&str> = expression.split_whitespace().collect();
+        if fields.len() != 5 {
+            return Err(CronError::new(
+                "",
+                format!(
+                    "필드가 5개(분 시 일 월 요일)여야 하지만 {}개입니다",
+                    fields.len()
+                ),
+            ));
+        }
+
+        let (weekdays, nth_weekdays) = parse_weekday_field(fields[4])?;
+        let expr = Self {
+            minutes: parse_field(fields[0], &MINUTE)?,
+            hours: parse_field(fields[1], &HOUR)?,
+            days: parse_field(fields[2], &DAY_OF_MONTH)?,
+            months: parse_field(fields[3], &MONTH)?,
+            weekdays,
+            nth_weekdays,
+            day_restricted: !fields[2].starts_with('*'),
+            weekday_restricted: !fields[4].starts_with('*'),
+        };
+
+        // 2월 30일처럼 실행될 날이 없는 표현식 확인 (윤년을 포함하는 구간에서 검사)
+        let leap_year = NaiveDate::from_ymd_opt(2024, 1, 1)
+            .unwrap()
+            .and_time(NaiveTime::MIN);
+        if expr.next_after(leap_year).is_none() {
+            return Err(CronError::new("", "실행되는 날짜가 없는 표현식입니다"));
+        }
+        Ok(expr)
+    }
+
+    fn matches_date(&self, date: NaiveDate) -> bool {
+        if !has_bit(self.months, date.month()) {
+            return false;
+        }
+
+        let day = date.day();
+        let weekday = date.weekday().num_days_from_sunday();
+        let day_match = has_bit(self.days, day);
+        let weekday_match = has_bit(self.weekdays, weekday)
+            || self
+                .nth_weekdays
+                .iter()
+                .any(|&(w, n)| w == weekday && (day - 1) / 7 + 1 == n);
+
+        // 일과 요일이 모두 지정되면 둘 중 하나만 맞아도 실행 (일반 cron과 동일)
+        match (self.day_restricted, self.weekday_restricted) {
+            (true, true) => day_match || weekday_match,
+            (true, false) => day_match,
+            (false, true) => weekday_match,
+            (false, false) => true,
+        }
+    }
+
+    /// `after` 이후(미포함) 첫 실행 시각
+    pub fn next_after(&self, after: NaiveDateTime) -> Option<NaiveDateTime> {
+        let start = truncate_to_minute(after) + chrono::Duration::minutes(1);
+        let mut date = start.date();
+
+        for _ in 0..MAX_SEARCH_DAYS {
+            if self.matches_date(date) {
+                let (from_hour, from_minute) = if date == start.date() {
+                    (start.hour(), start.minute())
+                } else {
+                    (0, 0)
+                };
+
+                for hour in (from_hour..24).filter(|&h| has_bit(self.hours, h)) {
+                    let first_minute = if hour == from_hour { from_minute } else { 0 };
+                    if let Some(minute) = (first_minute..60).find(|&m| has_bit(self.minutes, m)) {
+                        return date.and_hms_opt(hour, minute, 0);
+                    }
+                }
+            }
+            date = date.succ_opt()?;
+        }
+        None
+    }
+
+    /// `at` 이전(포함) 마지막 실행 시각
+    pub fn prev_at_or_before(&self, at: NaiveDateTime) -> Option<NaiveDateTime> {
+        let end = truncate_to_minute(at);
+        let mut date = end.date();
+
+        for _ in 0..MAX_SEARCH_DAYS {
+            if self.matches_date(date) {
+                let (to_hour, to_minute) = if date == end.date() {
+                    (end.hour(), end.minute())
+                } else {
+                    (23, 59)
+                };
+
+                for hour in (0..=to_hour).rev().filter(|&h| has_bit(self.hours, h)) {
+                    let last_minute = if hour == to_hour { to_minute } else { 59 };
+                    if let Some(minute) =
+                        (0..=last_minute).rev().find(|&m| has_bit(self.minutes, m))
+                    {
+                        return date.and_hms_opt(hour, minute, 0);
+                    }
+                }
+            }
+            date = date.pred_opt()?;
+        }
+        None
+    }
+
+    /// 현지 시간 기준 `after` 이후 첫 실행 시각 (서머타임으로 존재하지 않는 시각은 건너뜀)
+    pub fn next_local_after(&self, after: &DateTime<Local>) -> Option<DateTime<Local>> {
+        let mut cursor = after.naive_local();
+        loop {
+            cursor = self.next_after(cursor)?;
+            if let Some(time) = Local.from_local_datetime(&cursor).earliest() {
+                if time > *after {
+                    return Some(time);
+                }
+            }
+        }
+    }
+}
+
+fn has_bit(mask: u64, value: u32) -> bool {
+    mask & (1 << value) != 0
+}
+
+fn truncate_to_minute(time: NaiveDateTime) -> NaiveDateTime {
+    let minute = NaiveTime::from_hms_opt(time.hour(), time.minute(), 0).unwrap();
+    time.date().and_time(minute)
+}
+
+fn parse_field(text: &str, spec: &FieldSpec) -> Result<u64, CronError> {
+    let mut mask = 0;
+
+    for part in text.split(',') {
+        if part.is_empty() {
+            return Err(CronError::new(spec.name, "비어 있는 항목이 있습니다"));
+        }
+
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => {
+                let step: u32 = step.parse().ok().filter(|&step| step > 0).ok_or_else(|| {
+                    CronError::new(
+                        spec.name,
+                        format!("간격 '{}'은(는) 1 이상의 숫자여야 합니다", step),
+                    )
+                })?;
+                (range, Some(step))
+            }
+            None => (part, None),
+        };
+
+        let (low, high) = if range == "*" {
+            (spec.min, spec.max)
+        } else if let Some((low, high)) = range.split_once('-') {
+            (spec.value(low)?, spec.value(high)?)
+        } else {
+            let value = spec.value(range)?;
+            // `5/15`처럼 시작 값만 있으면 최대값까지 반복
+            (value, if step.is_some() { spec.max } else { value })
+        };
+
+        if low > high {
+            return Err(CronError::new(
+                spec.name,
+                format!("범위 '{}'의 시작이 끝보다 큽니다", range),
+            ));
+        }
+
+        for value in (low..=high).step_by(step.unwrap_or(1) as usize) {
+            mask |= 1 << value;
+        }
+    }
+
+    Ok(mask)
+}
+
+fn parse_weekday_field(text: &str) -> Result<(u64, Vec<(u32, u32)>), CronError> {
+    let mut plain = Vec::new();
+    let mut nth = Vec::new();
+
+    for part in text.split(',') {
+        match part.split_once('#') {
+            Some((weekday, n)) => {
+                let weekday = DAY_OF_WEEK.value(weekday)? % 7;
+                let n: u32 = n
+                    .parse()
+                    .ok()
+                    .filter(|n| (1..=5).contains(n))
+                    .ok_or_else(|| {
+                        CronError::new(
+                            DAY_OF_WEEK.name,
+                            format!("'#{}'은(는) 1에서 5 사이여야 합니다", n),
+                        )
+                    })?;
+                nth.push((weekday, n));
+            }
+            None => plain.push(part),
+        }
+    }
+
+    let mut mask = if plain.is_empty() {
+        0
+    } else {
+        parse_field(&plain.join(","), &DAY_OF_WEEK)?
+    };
+
+    // 7은 일요일(0)과 같음
+    if has_bit(mask, 7) {
+        mask = (mask & !(1 << 7)) | 1;
+    }
+    Ok((mask, nth))
+}
+
+/// 시작 표현식이 실행된 뒤 중지 표현식이 실행되기 전까지를 동작 구간으로 봄
+#[derive(Debug, Clone)]
+pub struct CronWindow {
+    pub start: CronExpr,
+    pub stop: CronExpr,
+}
+
+impl CronWindow {
+    pub fn parse(trigger: &CronTrigger) -> Result<Self, CronError> {
+        let prefix =
+            |e: CronError, which: &str| CronError::new("", format!("{} 표현식의 {}", which, e));
+
+        Ok(Self {
+            start: CronExpr::parse(&trigger.start).map_err(|e| prefix(e, "시작"))?,
+            stop: CronExpr::parse(&trigger.stop).map_err(|e| prefix(e, "중지"))?,
+        })
+    }
+
+    pub fn is_open(&self, now: NaiveDateTime) -> bool {
+        match (
+            self.start.prev_at_or_before(now),
+            self.stop.prev_at_or_before(now),
+        ) {
+            (Some(started), Some(stopped)) => started > stopped,
+            (Some(_), None) => true,
+            _ => false,
+        }
+    }
+}
+
+/// 트리거별 해석 결과 (트리거 문자열이 바뀐 경우에만 다시 해석)
+#[derive(Debug, Default)]
+pub struct CronWindows {
+    triggers: Vec<CronTrigger>,
+    windows: Vec<CronWindow>,
+    errors: Vec<Option<CronError>>,
+}
+
+impl CronWindows {
+    pub fn update(&mut self, triggers: &[CronTrigger]) {
+        if self.triggers == triggers {
+            return;
+        }
+
+        self.triggers = triggers.to_vec();
+        self.windows.clear();
+        self.errors.clear();
+        for trigger in triggers {
+            match CronWindow::parse(trigger) {
+                Ok(window) => {
+                    self.windows.push(window);
+                    self.errors.push(None);
+                }
+                Err(e) => self.errors.push(Some(e)),
+            }
+        }
+    }
+
+    /// 올바르게 해석된 구간 목록 (오류가 있는 트리거는 제외)
+    pub fn windows(&self) -> &[CronWindow] {
+        &self.windows
+    }
+
+    /// `index`번째 트리거의 해석 오류
+    pub fn error(&self, index: usize) -> Option<&CronError> {
+        self.errors.get(index)?.as_ref()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CronEvent {
+    Start,
+    Stop,
+}
+
+impl CronEvent {
+    pub fn label(&self) -> &'static str {
+        match self {
+            CronEvent::Start => "시작",
+            CronEvent::Stop => "중지",
+        }
+    }
+}
+
+/// 주입된 시계로 cron 구간을 평가하여 시작/중지 시점을 알려줌
+pub struct CronEvaluator<C: WallClock> {
+    clock: C,
+    was_open: bool,
+}
+
+impl<C: WallClock> CronEvaluator<C> {
+    pub fn new(clock: C) -> Self {
+        Self {
+            clock,
+            was_open: false,
+        }
+    }
+
+    /// 구간 상태가 바뀐 경우에만 이벤트를 반환
+    pub fn poll(&mut self, windows: &[CronWindow]) -> Option<CronEvent> {
+        let now = self.clock.now().naive_local();
+        let open = windows.iter().any(|window| window.is_open(now));
+
+        match (open, self.was_open) {
+            (true, false) => {
+                self.was_open = true;
+                Some(CronEvent::Start)
+            }
+            (false, true) => {
+                self.was_open = false;
+                Some(CronEvent::Stop)
+            }
+            _ => None,
+        }
+    }
+
+    /// 앞으로 실행될 시작/중지 시각 (가까운 순서로 최대 `count`개)
+    pub fn upcoming(
+        &self,
+        windows: &[CronWindow],
+        count: usize,
+    ) -> Vec<(DateTime<Local>, CronEvent)> {
+        let now = self.clock.now();
+        let mut fires = Vec::new();
+
+        for window in windows {
+            for (expr, event) in [
+                (&window.start, CronEvent::Start),
+                (&window.stop, CronEvent::Stop),
+            ] {
+                let mut cursor = now;
+                for _ in 0..count {
+                    match expr.next_local_after(&cursor) {
+                        Some(time) => {
+                            fires.push((time, event));
+                            cursor = time;
+                        }
+                        None => break,
+                    }
+                }
+            }
+        }
+
+        fires.sort_by_key(|(time, _)| *time);
+        fires.truncate(count);
+        fires
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::timer::clock::{test_time, ManualClock};
+    use std::time::Duration;
+
+    fn at(month: u32, day: u32, hour: u32, minute: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2024, month, day)
+            .unwrap()
+            .and_hms_opt(hour, minute, 0)
+            .unwrap()
+    }
+
+    fn next(expression: &str, after: NaiveDateTime) -> NaiveDateTime {
+        CronExpr::parse(expression)
+            .unwrap()
+            .next_after(after)
+            .unwrap()
+    }
+
+    fn error(expression: &str) -> String {
+        CronExpr::parse(expression).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn rejects_invalid_expressions() {
+        assert_eq!(
+            error("0 8 * *"),
+            "필드가 5개(분 시 일 월 요일)여야 하지만 4개입니다"
+        );
+        assert_eq!(
+            error("60 * * * *"),
+            "분 필드: 60은(는) 허용 범위(0-59)를 벗어났습니다"
+        );
+        assert_eq!(
+            error("*/0 * * * *"),
+            "분 필드: 간격 '0'은(는) 1 이상의 숫자여야 합니다"
+        );
+        assert_eq!(
+            error("0 5-1 * * *"),
+            "시 필드: 범위 '5-1'의 시작이 끝보다 큽니다"
+        );
+        assert_eq!(error("0 0 1,,2 * *"), "일 필드: 비어 있는 항목이 있습니다");
+        assert_eq!(
+            error("0 0 * FOO *"),
+            "월 필드: 'FOO'은(는) 올바른 값이 아닙니다"
+        );
+        assert_eq!(
+            error("0 0 * * MON#6"),
+            "요일 필드: '#6'은(는) 1에서 5 사이여야 합니다"
+        );
+        assert_eq!(error("0 0 30 2 *"), "실행되는 날짜가 없는 표현식입니다");
+    }
+
+    #[test]
+    fn nth_weekday_of_month() {
+        // 2024-03-04, 2024-04-01은 각 달의 첫 번째 월요일
+        assert_eq!(next("0 8 * * MON#1", at(3, 1, 0, 0)), at(3, 4, 8, 0));
+        assert_eq!(next("0 8 * * MON#1", at(3, 4, 8, 0)), at(4, 1, 8, 0));
+        assert_eq!(next("30 17 * * fri#2", at(3, 1, 0, 0)), at(3, 8, 17, 30));
+        // 다섯 번째 금요일이 없는 달은 건너뜀
+        assert_eq!(next("0 0 * * FRI#5", at(3, 1, 0, 0)), at(3, 29, 0, 0));
+        assert_eq!(next("0 0 * * FRI#5", at(3, 29, 0, 0)), at(5, 31, 0, 0));
+    }
+
+    #[test]
+    fn day_of_month_or_day_of_week() {
+        // 2024-03-01은 금요일
+        assert_eq!(next("0 0 13 * FRI", at(3, 1, 0, 0)), at(3, 8, 0, 0));
+        assert_eq!(next("0 0 13 * FRI", at(3, 8, 0, 0)), at(3, 13, 0, 0));
+        assert_eq!(next("0 0 13 * *", at(3, 1, 0, 0)), at(3, 13, 0, 0));
+        assert_eq!(next("0 0 * * FRI", at(3, 8, 0, 0)), at(3, 15, 0, 0));
+        // 7도 일요일
+        assert_eq!(next("0 0 * * 7", at(3, 1, 0, 0)), at(3, 3, 0, 0));
+    }
+
+    #[test]
+    fn window_is_open_between_start_and_stop() {
+        let window = CronWindow::parse(&CronTrigger::default()).unwrap();
+        assert!(!window.is_open(at(3, 4, 7, 59)));
+        assert!(window.is_open(at(3, 4, 8, 0)));
+        assert!(window.is_open(at(3, 4, 11, 59)));
+        assert!(!window.is_open(at(3, 4, 12, 0)));
+        assert!(!window.is_open(at(3, 11, 9, 0)));
+
+        let night = CronWindow::parse(&CronTrigger {
+            start: "0 22 * * *".to_string(),
+            stop: "0 6 * * *".to_string(),
+        })
+        .unwrap();
+        assert!(night.is_open(at(3, 4, 23, 0)));
+        assert!(night.is_open(at(3, 5, 5, 59)));
+        assert!(!night.is_open(at(3, 5, 6, 0)));
+    }
+
+    #[test]
+    fn evaluator_reports_changes_and_upcoming_fires() {
+        let windows = [CronWindow::parse(&CronTrigger::default()).unwrap()];
+        let mut evaluator = CronEvaluator::new(ManualClock::new(test_time(7, 0)));
+        assert_eq!(evaluator.poll(&windows), None);
+
+        let upcoming = evaluator.upcoming(&windows, 3);
+        let expected_april = Local.with_ymd_and_hms(2024, 4, 1, 8, 0, 0).unwrap();
+        assert_eq!(
+            upcoming,
+            [
+                (test_time(8, 0), CronEvent::Start),
+                (test_time(12, 0), CronEvent::Stop),
+                (expected_april, CronEvent::Start),
+            ]
+        );
+
+        evaluator.clock.advance(Duration::from_secs(60 * 60));
+        assert_eq!(evaluator.poll(&windows), Some(CronEvent::Start));
+        assert_eq!(evaluator.poll(&windows), None);
+
+        evaluator.clock.advance(Duration::from_secs(4 * 60 * 60));
+        assert_eq!(evaluator.poll(&windows), Some(CronEvent::Stop));
+        assert_eq!(evaluator.poll(&[]), None);
+    }
+
+    #[test]
+    fn windows_keep_errors_per_trigger() {
+        let mut triggers = vec![
+            CronTrigger::default(),
+            CronTrigger {
+                start: "0 8 * *".to_string(),
+                stop: "0 12 * * *".to_string(),
+            },
+        ];
+        let mut windows = CronWindows::default();
+        windows.update(&triggers);
+        assert_eq!(windows.windows().len(), 1);
+        assert!(windows.error(0).is_none());
+        assert!(windows
+            .error(1)
+            .unwrap()
+            .to_string()
+            .starts_with("시작 표현식의"));
+
+        triggers[1].start = "0 8 * * *".to_string();
+        windows.update(&triggers);
+        assert_eq!(windows.windows().len(), 2);
+        assert!(windows.error(1).is_none());
+    }
+}
@@ -0,0 +1,150 @@
+use super::Settings;
+use anyhow::Result;
+use serde_json::Value;
+use std::path::Path;
+
+/// 설정 파일 형식 (확장자로 구분)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigFormat {
+    Json,
+    Toml,
+    Yaml,
+}
+
+impl ConfigFormat {
+    /// 같은 디렉토리에 여러 파일이 있을 때 앞쪽 형식을 우선 사용
+    pub const ALL: [ConfigFormat; 3] = [ConfigFormat::Toml, ConfigFormat::Yaml, ConfigFormat::Json];
+
+    /// 저장할 때 사용하는 확장자
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ConfigFormat::Json => "json",
+            ConfigFormat::Toml => "toml",
+            ConfigFormat::Yaml => "yaml",
+        }
+    }
+
+    /// 불러올 때 인식하는 확장자
+    pub fn extensions(&self) -> &'static [&'static str] {
+        match self {
+            ConfigFormat::Json => &["json"],
+            ConfigFormat::Toml => &["toml"],
+            ConfigFormat::Yaml => &["yaml", "yml"],
+        }
+    }
+
+    /// 형식 이름 또는 확장자로 찾음 (대소문자 무시)
+    pub fn from_name(name: &str) -> Option<Self> {
+        let name = name.to_ascii_lowercase();
+        Self::ALL
+            .into_iter()
+            .find(|format| format.extensions().contains(&name.as_str()))
+    }
+
+    pub fn from_path(path: &Path) -> Option<Self> {
+        path.extension()
+            .and_then(|ext| ext.to_str())
+            .and_then(Self::from_name)
+    }
+
+    /// 파일 내용을 JSON 값으로 읽음 (버전 변환과 항목별 해석은 JSON 값 기준으로 수행)
+    pub fn parse(&self, content: &str) -> Result<Value> {
+        let value = match self {
+            ConfigFormat::Json => serde_json::from_str(content)?,
+            ConfigFormat::Toml => toml::from_str(content)?,
+            ConfigFormat::Yaml => serde_yaml::from_str(content)?,
+        };
+        Ok(value)
+    }
+
+    pub fn serialize(&self, settings: &Settings) -> Result<String> {
+        let content = match self {
+            ConfigFormat::Json => serde_json::to_string_pretty(settings)?,
+            ConfigFormat::Toml => toml::to_string_pretty(settings)?,
+            ConfigFormat::Yaml => serde_yaml::to_string(settings)?,
+        };
+        Ok(content)
+    }
+}
+
+impl std::fmt::Display for ConfigFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            ConfigFormat::Json => "JSON",
+            ConfigFormat::Toml => "TOML",
+            ConfigFormat::Yaml => "YAML",
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::migrate;
+    use crate::config::TimeRange;
+    use crate::timer::ExpiryAction;
+    use chrono::NaiveTime;
+
+    fn sample() -> Settings {
+        let mut settings = Settings {
+            interval_seconds: 12.5,
+            expiry_actions: vec![
+                ExpiryAction::Notify {
+                    message: "끝".to_string(),
+                },
+                ExpiryAction::Stop,
+            ],
+            ..Settings::default()
+        };
+        let mut range = TimeRange::new(
+            NaiveTime::from_hms_opt(22, 0, 0).unwrap(),
+            NaiveTime::from_hms_opt(2, 0, 0).unwrap(),
+        );
+        range.profile = Some("야간".to_string());
+        settings.schedule.days[4].push(range);
+        settings
+    }
+
+    #[test]
+    fn round_trips_every_format() {
+        let settings = sample();
+        let expected = serde_json::to_value(&settings).unwrap();
+
+        for format in ConfigFormat::ALL {
+            let content = format.serialize(&settings).unwrap();
+            let (loaded, warnings) = migrate::parse_value(format.parse(&content).unwrap()).unwrap();
+            assert!(warnings.is_empty(), "{}: {:?}", format, warnings);
+            assert_eq!(
+                serde_json::to_value(&loaded).unwrap(),
+                expected,
+                "{}",
+                format
+            );
+        }
+    }
+
+    #[test]
+    fn detects_format_by_extension() {
+        assert_eq!(
+            ConfigFormat::from_path(Path::new("/a/config.yml")),
+            Some(ConfigFormat::Yaml)
+        );
+        assert_eq!(
+            ConfigFormat::from_path(Path::new("config.TOML")),
+            Some(ConfigFormat::Toml)
+        );
+        assert_eq!(ConfigFormat::from_path(Path::new("config.ini")), None);
+        assert_eq!(ConfigFormat::from_name("json"), Some(ConfigFormat::Json));
+    }
+
+    #[test]
+    fn hand_written_toml_uses_defaults_for_missing_keys() {
+        let content = "version = 2\ninterval_seconds = 45.0\n\n[cycle]\nenabled = true\n";
+        let (settings, warnings) =
+            migrate::parse_value(ConfigFormat::Toml.parse(content).unwrap()).unwrap();
+        assert!(warnings.is_empty());
+        assert_eq!(settings.interval_seconds, 45.0);
+        assert!(settings.cycle.enabled);
+        assert_eq!(settings.cycle.work_minutes, 25);
+    }
+}
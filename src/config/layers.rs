@@ -0,0 +1,475 @@
+use super::location::CONFIG_ENV;
+use super::Settings;
+use anyhow::Result;
+use serde_json::{Map, Value};
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// 설정값을 덮어쓰는 환경 변수의 접두사
+pub const ENV_PREFIX: &str = "AUTO_MOUSE_";
+
+/// 설정값을 정한 단계 (기본값 < 설정 파일 < 환경 변수 < 명령줄)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Origin {
+    Default,
+    /// 설정 파일 또는 프로필 (표시용 이름)
+    File(String),
+    /// 환경 변수 이름
+    Env(String),
+    /// 명령줄 옵션
+    Cli(String),
+}
+
+impl fmt::Display for Origin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Origin::Default => write!(f, "기본값"),
+            Origin::File(label) => write!(f, "파일 {}", label),
+            Origin::Env(name) => write!(f, "환경 변수 {}", name),
+            Origin::Cli(flag) => write!(f, "명령줄 {}", flag),
+        }
+    }
+}
+
+/// 설정 파일 위에 덮어쓸 값 하나
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Override {
+    /// 항목 이름 (`power.pause_on_battery` 형식)
+    pub key: String,
+    pub value: String,
+    pub origin: Origin,
+}
+
+/// 환경 변수와 명령줄에서 지정한 설정값 (뒤에 있는 값이 우선)
+#[derive(Debug, Clone, Default)]
+pub struct Overrides {
+    entries: Vec<Override>,
+}
+
+impl Overrides {
+    /// 환경 변수(`AUTO_MOUSE_*`) 다음에 명령줄 값을 쌓음
+    ///
+    /// 설정 항목에 해당하지 않는 환경 변수는 무시하고 경고로 반환
+    pub fn collect<I>(env: I, cli: Vec<Override>) -> (Self, Vec<String>)
+    where
+        I: IntoIterator<Item = (String, String)>,
+    {
+        let keys: BTreeMap<String, String> = keys()
+            .into_iter()
+            .map(|key| (env_name(&key), key))
+            .collect();
+        let mut entries = Vec::new();
+        let mut warnings = Vec::new();
+
+        let mut env: Vec<(String, String)> = env
+            .into_iter()
+            .filter(|(name, _)| name.starts_with(ENV_PREFIX) && name != CONFIG_ENV)
+            .collect();
+        env.sort();
+        for (name, value) in env {
+            match keys.get(&name) {
+                Some(key) => entries.push(Override {
+                    key: key.clone(),
+                    value,
+                    origin: Origin::Env(name),
+                }),
+                None => warnings.push(format!("알 수 없는 환경 변수를 무시합니다: {}", name)),
+            }
+        }
+
+        entries.extend(cli);
+        (Self { entries }, warnings)
+    }
+
+    /// 현재 프로세스의 환경 변수와 명령줄 값으로 만듦
+    pub fn from_env(cli: Vec<Override>) -> (Self, Vec<String>) {
+        Self::collect(std::env::vars(), cli)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// 설정에 덮어쓴 결과
+    ///
+    /// 형식이 맞지 않거나 허용 범위를 벗어난 값은 건너뛰어 아래 단계(파일 등)의 값을 유지하고 경고
+    pub fn apply(&self, settings: &Settings) -> (Settings, Vec<String>) {
+        let (settings, _, warnings) = self.apply_accepted(settings);
+        (settings, warnings)
+    }
+
+    /// `apply`와 같고, 건너뛰지 않고 적용한 값 목록을 함께 반환
+    fn apply_accepted(&self, settings: &Settings) -> (Settings, Vec<&Override>, Vec<String>) {
+        if self.is_empty() {
+            return (settings.clone(), Vec::new(), Vec::new());
+        }
+
+        let mut value = to_value(settings);
+        let mut accepted = Vec::new();
+        let mut warnings = Vec::new();
+        for entry in &self.entries {
+            let mut candidate = value.clone();
+            let parsed = parse_value(lookup(&candidate, &entry.key), &entry.value);
+            set_path(&mut candidate, &entry.key, parsed);
+
+            match check_override(&candidate, &entry.key) {
+                Ok(()) => {
+                    value = candidate;
+                    accepted.push(entry);
+                }
+                Err(reason) => warnings.push(format!(
+                    "{}의 {} 값 '{}'을(를) 무시합니다: {}",
+                    entry.origin, entry.key, entry.value, reason
+                )),
+            }
+        }
+
+        match serde_json::from_value(value) {
+            Ok(applied) => (applied, accepted, warnings),
+            Err(_) => (settings.clone(), Vec::new(), warnings),
+        }
+    }
+
+    /// 덮어쓴 값을 화면에서 바꾸지 않은 항목은 `base`(파일)의 값으로 되돌림
+    ///
+    /// 환경 변수나 명령줄 값이 설정을 저장할 때 파일에 섞여 들어가지 않도록 함
+    pub fn restore(&self, settings: &Settings, base: &Settings) -> Settings {
+        if self.is_empty() {
+            return settings.clone();
+        }
+
+        let applied = to_value(&self.apply(base).0);
+        let base = to_value(base);
+        let mut value = to_value(settings);
+        for entry in &self.entries {
+            if lookup(&value, &entry.key) == lookup(&applied, &entry.key) {
+                if let Some(original) = lookup(&base, &entry.key).cloned() {
+                    set_path(&mut value, &entry.key, original);
+                }
+            }
+        }
+        serde_json::from_value(value).unwrap_or_else(|_| settings.clone())
+    }
+}
+
+/// 각 항목의 최종 값과 그 값을 정한 단계
+pub struct Resolved {
+    pub settings: Settings,
+    pub origins: BTreeMap<String, Origin>,
+    pub warnings: Vec<String>,
+}
+
+impl Resolved {
+    /// 항목 이름, 값, 값을 정한 단계를 이름순으로 반환
+    pub fn entries(&self) -> Vec<(String, Value, Origin)> {
+        leaves(&to_value(&self.settings))
+            .into_iter()
+            .map(|(key, value)| {
+                let origin = self.origins.get(&key).cloned().unwrap_or(Origin::Default);
+                (key, value, origin)
+            })
+            .collect()
+    }
+}
+
+/// 파일에서 읽은 설정에 덮어쓸 값을 적용하고 항목마다 어느 단계에서 정해졌는지 기록
+///
+/// `file_keys`는 파일에 실제로 적혀 있던 항목 (나머지는 기본값)
+pub fn resolve(
+    file_settings: &Settings,
+    file_keys: &[String],
+    file_label: &str,
+    overrides: &Overrides,
+) -> Resolved {
+    let mut origins: BTreeMap<String, Origin> = keys()
+        .into_iter()
+        .map(|key| (key, Origin::Default))
+        .collect();
+
+    for key in file_keys {
+        if let Some(origin) = origins.get_mut(key) {
+            *origin = Origin::File(file_label.to_string());
+        }
+    }
+
+    // 건너뛴 값은 아래 단계의 값이 남으므로 그 단계를 그대로 표시
+    let (settings, accepted, warnings) = overrides.apply_accepted(file_settings);
+    for entry in accepted {
+        origins.insert(entry.key.clone(), entry.origin.clone());
+    }
+    Resolved {
+        settings,
+        origins,
+        warnings,
+    }
+}
+
+/// 설정 파일 내용에 적혀 있는 항목 이름
+pub fn file_keys(value: &Value) -> Vec<String> {
+    leaves(value)
+        .into_keys()
+        .filter(|key| key != "version")
+        .collect()
+}
+
+/// 모든 설정 항목 이름 (`power.pause_on_battery` 형식)
+pub fn keys() -> Vec<String> {
+    file_keys(&to_value(&Settings::default()))
+}
+
+pub fn is_known_key(key: &str) -> bool {
+    keys().iter().any(|known| known == key)
+}
+
+/// 항목을 덮어쓰는 환경 변수 이름 (`.`은 `__`로 바꿈)
+pub fn env_name(key: &str) -> String {
+    format!(
+        "{}{}",
+        ENV_PREFIX,
+        key.replace('.', "__").to_ascii_uppercase()
+    )
+}
+
+/// `key=value` 형식의 명령줄 값을 해석
+pub fn parse_assignment(assignment: &str) -> Result<(String, String)> {
+    let (key, value) = assignment
+        .split_once('=')
+        .ok_or_else(|| anyhow::anyhow!("<항목>=<값> 형식이어야 합니다: {}", assignment))?;
+    let key = key.trim();
+    if !is_known_key(key) {
+        return Err(anyhow::anyhow!("알 수 없는 설정 항목: {}", key));
+    }
+    Ok((key.to_string(), value.to_string()))
+}
+
+/// 설정을 항목 이름과 값의 목록으로 펼침
+pub fn flatten(settings: &Settings) -> BTreeMap<String, Value> {
+    let mut values = leaves(&to_value(settings));
+    values.remove("version");
+    values
+}
+
+fn to_value(settings: &Settings) -> Value {
+    serde_json::to_value(settings).unwrap_or(Value::Null)
+}
+
+/// 객체는 펼치고 배열과 값은 그대로 둔 항목 목록
+fn leaves(value: &Value) -> BTreeMap<String, Value> {
+    fn walk(prefix: &str, value: &Value, out: &mut BTreeMap<String, Value>) {
+        match value {
+            Value::Object(map) => {
+                for (key, child) in map {
+                    let path = if prefix.is_empty() {
+                        key.clone()
+                    } else {
+                        format!("{}.{}", prefix, key)
+                    };
+                    walk(&path, child, out);
+                }
+            }
+            _ => {
+                out.insert(prefix.to_string(), value.clone());
+            }
+        }
+    }
+
+    let mut out = BTreeMap::new();
+    walk("", value, &mut out);
+    out
+}
+
+fn lookup<'a>(value: &'a Value, key: &str) -> Option<&'a Value> {
+    key.split('.')
+        .try_fold(value, |value, part| value.get(part))
+}
+
+fn set_path(value: &mut Value, key: &str, new: Value) {
+    let mut current = value;
+    let mut parts = key.split('.').peekable();
+    while let Some(part) = parts.next() {
+        if !current.is_object() {
+            *current = Value::Object(Map::new());
+        }
+        let Value::Object(map) = current else {
+            return;
+        };
+        if parts.peek().is_none() {
+            map.insert(part.to_string(), new);
+            return;
+        }
+        current = map.entry(part).or_insert(Value::Null);
+    }
+}
+
+/// 값 하나를 덮어쓴 설정이 해석되고 그 항목이 허용 범위 안에 있는지 확인
+fn check_override(value: &Value, key: &str) -> Result<(), String> {
+    let settings: Settings = serde_json::from_value(value.clone()).map_err(|e| e.to_string())?;
+    match settings.validate() {
+        Err(errors) => match errors.iter().find(|error| error.field == key) {
+            Some(error) => Err(error.to_string()),
+            None => Ok(()),
+        },
+        Ok(()) => Ok(()),
+    }
+}
+
+/// 문자열 항목은 그대로, 그 외는 JSON 값(숫자, true/false, 배열 등)으로 해석
+fn parse_value(current: Option<&Value>, raw: &str) -> Value {
+    match current {
+        Some(Value::String(_)) => Value::String(raw.to_string()),
+        _ => serde_json::from_str(raw).unwrap_or_else(|_| Value::String(raw.to_string())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cli(key: &str, value: &str) -> Override {
+        Override {
+            key: key.to_string(),
+            value: value.to_string(),
+            origin: Origin::Cli("--set".to_string()),
+        }
+    }
+
+    fn env(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+        pairs
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn later_layers_win() {
+        let file = Settings {
+            interval_seconds: 20.0,
+            move_distance: 5,
+            ..Settings::default()
+        };
+        let file_keys = vec!["interval_seconds".to_string(), "move_distance".to_string()];
+        let (overrides, warnings) = Overrides::collect(
+            env(&[
+                ("AUTO_MOUSE_MOVE_DISTANCE", "7"),
+                ("AUTO_MOUSE_POWER__PAUSE_ON_BATTERY", "true"),
+                ("AUTO_MOUSE_NOPE", "1"),
+                ("AUTO_MOUSE_CONFIG", "/tmp/other.json"),
+                ("HOME", "/root"),
+            ]),
+            vec![cli("power.pause_on_battery", "false")],
+        );
+        assert_eq!(
+            warnings,
+            vec!["알 수 없는 환경 변수를 무시합니다: AUTO_MOUSE_NOPE"]
+        );
+
+        let resolved = resolve(&file, &file_keys, "config.json", &overrides);
+        assert!(resolved.warnings.is_empty());
+        assert_eq!(resolved.settings.interval_seconds, 20.0);
+        assert_eq!(resolved.settings.move_distance, 7);
+        assert!(!resolved.settings.power.pause_on_battery);
+
+        let origins = &resolved.origins;
+        assert_eq!(
+            origins["interval_seconds"],
+            Origin::File("config.json".into())
+        );
+        assert_eq!(
+            origins["move_distance"],
+            Origin::Env("AUTO_MOUSE_MOVE_DISTANCE".into())
+        );
+        assert_eq!(
+            origins["power.pause_on_battery"],
+            Origin::Cli("--set".into())
+        );
+        assert_eq!(origins["enable_sound"], Origin::Default);
+    }
+
+    #[test]
+    fn string_fields_keep_raw_text() {
+        let (overrides, _) = Overrides::collect(
+            env(&[("AUTO_MOUSE_PROCESS_WATCH__TARGET", "12345")]),
+            Vec::new(),
+        );
+        let (settings, warnings) = overrides.apply(&Settings::default());
+        assert!(warnings.is_empty());
+        assert_eq!(settings.process_watch.target, "12345");
+    }
+
+    #[test]
+    fn invalid_override_keeps_lower_layer_value() {
+        let file = Settings {
+            move_distance: 5,
+            interval_seconds: 20.0,
+            ..Settings::default()
+        };
+
+        let (overrides, _) = Overrides::collect(Vec::new(), vec![cli("move_distance", "9999")]);
+        let (settings, warnings) = overrides.apply(&file);
+        assert_eq!(settings.move_distance, 5);
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].starts_with("명령줄 --set의 move_distance 값 '9999'"));
+
+        let file_keys = vec!["move_distance".to_string(), "interval_seconds".to_string()];
+        let resolved = resolve(&file, &file_keys, "config.json", &overrides);
+        assert_eq!(resolved.settings.move_distance, 5);
+        assert_eq!(
+            resolved.origins["move_distance"],
+            Origin::File("config.json".to_string())
+        );
+
+        // 잘못된 명령줄 값은 건너뛰고 환경 변수 값을 유지, 다른 항목은 그대로 적용
+        let (overrides, _) = Overrides::collect(
+            env(&[
+                ("AUTO_MOUSE_MOVE_DISTANCE", "7"),
+                ("AUTO_MOUSE_INTERVAL_SECONDS", "abc"),
+            ]),
+            vec![cli("move_distance", "-3"), cli("enable_sound", "true")],
+        );
+        let (settings, warnings) = overrides.apply(&file);
+        assert_eq!(settings.move_distance, 7);
+        assert_eq!(settings.interval_seconds, 20.0);
+        assert!(settings.enable_sound);
+        assert_eq!(warnings.len(), 2);
+
+        let resolved = resolve(&file, &file_keys, "config.json", &overrides);
+        assert_eq!(
+            resolved.origins["move_distance"],
+            Origin::Env("AUTO_MOUSE_MOVE_DISTANCE".to_string())
+        );
+        assert_eq!(
+            resolved.origins["interval_seconds"],
+            Origin::File("config.json".to_string())
+        );
+        assert_eq!(
+            resolved.origins["enable_sound"],
+            Origin::Cli("--set".to_string())
+        );
+    }
+
+    #[test]
+    fn restore_keeps_overrides_out_of_saved_file() {
+        let base = Settings::default();
+        let (overrides, _) = Overrides::collect(
+            Vec::new(),
+            vec![cli("interval_seconds", "5"), cli("move_distance", "3")],
+        );
+        let (mut edited, _) = overrides.apply(&base);
+        // 화면에서 이동 거리만 바꿈
+        edited.move_distance = 20;
+
+        let saved = overrides.restore(&edited, &base);
+        assert_eq!(saved.interval_seconds, base.interval_seconds);
+        assert_eq!(saved.move_distance, 20);
+    }
+
+    #[test]
+    fn assignment_requires_known_key() {
+        assert_eq!(
+            parse_assignment("cycle.work_minutes=50").unwrap(),
+            ("cycle.work_minutes".to_string(), "50".to_string())
+        );
+        assert!(parse_assignment("cycle.nope=1").is_err());
+        assert!(parse_assignment("interval_seconds").is_err());
+    }
+}
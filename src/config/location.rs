@@ -0,0 +1,233 @@
+use super::ConfigFormat;
+use anyhow::Result;
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+/// 설정 파일 경로를 지정하는 환경 변수
+pub const CONFIG_ENV: &str = "AUTO_MOUSE_CONFIG";
+
+static LOCATION: OnceLock<ConfigLocation> = OnceLock::new();
+
+/// 설정 파일을 찾는 위치 (`--config` > `AUTO_MOUSE_CONFIG` > 실행 파일 옆 > 사용자 설정 디렉토리)
+///
+/// 프로필, 타이머 상태 등 부가 파일은 설정 파일과 같은 디렉토리에 저장 (`sidecar` 참고)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigLocation {
+    /// `--config` 또는 `AUTO_MOUSE_CONFIG`로 지정한 파일
+    Explicit(PathBuf),
+    /// 실행 파일 옆에 설정 파일이 있는 경우 (포터블 모드)
+    Portable(PathBuf),
+    /// 사용자 설정 디렉토리 아래 `auto-mouse`
+    Standard(PathBuf),
+}
+
+impl ConfigLocation {
+    /// 우선순위에 따라 위치를 결정
+    pub fn resolve(
+        cli: Option<&Path>,
+        env: Option<OsString>,
+        exe_dir: Option<&Path>,
+        standard_dir: Option<PathBuf>,
+    ) -> Result<Self> {
+        let explicit = cli
+            .map(Path::to_path_buf)
+            .or_else(|| env.filter(|value| !value.is_empty()).map(PathBuf::from));
+        if let Some(path) = explicit {
+            return Ok(ConfigLocation::Explicit(absolute(path)?));
+        }
+
+        if let Some(dir) = exe_dir.filter(|dir| has_config_file(dir)) {
+            return Ok(ConfigLocation::Portable(dir.to_path_buf()));
+        }
+
+        let mut dir =
+            standard_dir.ok_or_else(|| anyhow::anyhow!("설정 디렉토리를 찾을 수 없습니다"))?;
+        dir.push("auto-mouse");
+        Ok(ConfigLocation::Standard(dir))
+    }
+
+    /// 명령줄 값과 현재 환경으로 위치를 정하고 이번 실행 동안 사용하도록 고정
+    pub fn init(cli: Option<&Path>) -> Result<&'static Self> {
+        let location = Self::from_env(cli)?;
+        Ok(LOCATION.get_or_init(|| location))
+    }
+
+    /// 이번 실행에서 사용하는 위치 (`init` 전이면 환경만으로 결정)
+    pub fn current() -> Result<Self> {
+        match LOCATION.get() {
+            Some(location) => Ok(location.clone()),
+            None => Self::from_env(None),
+        }
+    }
+
+    fn from_env(cli: Option<&Path>) -> Result<Self> {
+        let exe = std::env::current_exe().ok();
+        Self::resolve(
+            cli,
+            std::env::var_os(CONFIG_ENV),
+            exe.as_deref().and_then(Path::parent),
+            dirs::config_dir(),
+        )
+    }
+
+    /// 설정 파일과 부가 파일이 있는 디렉토리
+    pub fn dir(&self) -> PathBuf {
+        match self {
+            ConfigLocation::Explicit(path) => path
+                .parent()
+                .map(Path::to_path_buf)
+                .unwrap_or_else(|| PathBuf::from(".")),
+            ConfigLocation::Portable(dir) | ConfigLocation::Standard(dir) => dir.clone(),
+        }
+    }
+
+    /// 설정 파일과 함께 쓰는 부가 파일의 경로
+    ///
+    /// 지정한 파일은 같은 디렉토리의 다른 설정 파일과 겹치지 않도록 파일 이름을 앞에 붙임
+    /// (`/a/work.json`이면 `/a/work.profiles.json`)
+    pub fn sidecar(&self, name: &str) -> PathBuf {
+        let stem = self
+            .explicit_file()
+            .and_then(Path::file_stem)
+            .map(|stem| stem.to_string_lossy());
+        match stem {
+            Some(stem) => self.dir().join(format!("{}.{}", stem, name)),
+            None => self.dir().join(name),
+        }
+    }
+
+    /// 지정한 파일 (디렉토리에서 `config.*`를 찾는 경우 None)
+    pub fn explicit_file(&self) -> Option<&Path> {
+        match self {
+            ConfigLocation::Explicit(path) => Some(path),
+            _ => None,
+        }
+    }
+
+    /// 인스턴스마다 다른 이름이 필요한 곳(명령 소켓 등)에 붙일 구분자
+    ///
+    /// 기본 위치는 None이라 기존 이름을 그대로 사용. Rust 버전이 바뀌어도 같은 값이 나오도록
+    /// 경로에 FNV-1a 해시를 사용함
+    pub fn instance_id(&self) -> Option<String> {
+        let key = match self {
+            ConfigLocation::Standard(_) => return None,
+            ConfigLocation::Explicit(path) => path,
+            ConfigLocation::Portable(dir) => dir,
+        };
+        Some(format!("{:016x}", fnv1a(key.to_string_lossy().as_bytes())))
+    }
+
+    pub fn describe(&self) -> String {
+        match self {
+            ConfigLocation::Explicit(path) => format!("{} (지정한 파일)", path.display()),
+            ConfigLocation::Portable(dir) => format!("{} (포터블)", dir.display()),
+            ConfigLocation::Standard(dir) => dir.display().to_string(),
+        }
+    }
+}
+
+/// 디렉토리에 `config.json`/`config.toml`/`config.yaml` 중 하나가 있는지
+fn has_config_file(dir: &Path) -> bool {
+    ConfigFormat::ALL
+        .iter()
+        .flat_map(|format| format.extensions())
+        .any(|ext| dir.join(format!("config.{}", ext)).is_file())
+}
+
+/// 64비트 FNV-1a 해시
+fn fnv1a(bytes: &[u8]) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0100_0000_01b3;
+
+    bytes.iter().fold(OFFSET_BASIS, |hash, &byte| {
+        (hash ^ byte as u64).wrapping_mul(PRIME)
+    })
+}
+
+/// 상대 경로는 현재 디렉토리 기준으로 바꿔, 인스턴스 구분자가 실행 위치에 따라 달라지지 않게 함
+fn absolute(path: PathBuf) -> Result<PathBuf> {
+    if path.is_absolute() {
+        Ok(path)
+    } else {
+        Ok(std::env::current_dir()?.join(path))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::temp_dir;
+
+    #[test]
+    fn command_line_beats_environment() {
+        let location = ConfigLocation::resolve(
+            Some(Path::new("/a/cli.toml")),
+            Some("/b/env.json".into()),
+            None,
+            Some(PathBuf::from("/home/u/.config")),
+        )
+        .unwrap();
+        assert_eq!(location, ConfigLocation::Explicit("/a/cli.toml".into()));
+        assert_eq!(location.dir(), PathBuf::from("/a"));
+
+        let location =
+            ConfigLocation::resolve(None, Some("/b/env.json".into()), None, None).unwrap();
+        assert_eq!(location, ConfigLocation::Explicit("/b/env.json".into()));
+    }
+
+    #[test]
+    fn portable_only_when_config_next_to_executable() {
+        let exe_dir = temp_dir("location-portable");
+        let standard = Some(PathBuf::from("/home/u/.config"));
+
+        let location = ConfigLocation::resolve(None, None, Some(&exe_dir), standard.clone());
+        assert_eq!(
+            location.unwrap(),
+            ConfigLocation::Standard("/home/u/.config/auto-mouse".into())
+        );
+
+        std::fs::write(exe_dir.join("config.yaml"), "").unwrap();
+        let location = ConfigLocation::resolve(None, Some("".into()), Some(&exe_dir), standard);
+        assert_eq!(location.unwrap(), ConfigLocation::Portable(exe_dir.clone()));
+
+        let _ = std::fs::remove_dir_all(&exe_dir);
+    }
+
+    #[test]
+    fn instances_get_distinct_ids() {
+        let standard = ConfigLocation::Standard("/home/u/.config/auto-mouse".into());
+        let a = ConfigLocation::Explicit("/a/config.json".into());
+        let b = ConfigLocation::Explicit("/b/config.json".into());
+        assert_eq!(standard.instance_id(), None);
+        assert_ne!(a.instance_id(), b.instance_id());
+        assert_eq!(a.instance_id(), a.clone().instance_id());
+        // 실행할 때마다, 빌드가 달라도 같은 소켓 이름을 쓰도록 고정된 값
+        assert_eq!(a.instance_id().as_deref(), Some("fb5fa2ce1217ef54"));
+    }
+
+    #[test]
+    fn sidecars_are_named_after_explicit_file() {
+        let standard = ConfigLocation::Standard("/home/u/.config/auto-mouse".into());
+        let portable = ConfigLocation::Portable("/media/usb".into());
+        let work = ConfigLocation::Explicit("/a/work.json".into());
+        let home = ConfigLocation::Explicit("/a/home.toml".into());
+
+        assert_eq!(
+            standard.sidecar("profiles.json"),
+            PathBuf::from("/home/u/.config/auto-mouse/profiles.json")
+        );
+        assert_eq!(
+            portable.sidecar("timer_state.json"),
+            PathBuf::from("/media/usb/timer_state.json")
+        );
+        assert_eq!(
+            work.sidecar("timer_state.json"),
+            PathBuf::from("/a/work.timer_state.json")
+        );
+        assert_ne!(
+            work.sidecar("snooze_history.jsonl"),
+            home.sidecar("snooze_history.jsonl")
+        );
+    }
+}
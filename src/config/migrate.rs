@@ -0,0 +1,161 @@
+use super::{ConfigFormat, Settings};
+use anyhow::Result;
+use serde_json::{Map, Value};
+
+/// 현재 설정 파일 형식 버전
+pub const CURRENT_VERSION: u32 = 2;
+
+type Migration = fn(&mut Map<String, Value>);
+
+/// `MIGRATIONS[i]`는 버전 i+1의 설정을 버전 i+2로 변환
+const MIGRATIONS: [Migration; CURRENT_VERSION as usize - 1] = [v1_to_v2];
+
+/// v1: `version` 필드가 없던 설정
+///
+/// 이후 추가된 항목은 모두 기본값으로 채워지므로 구조 변경은 없음
+fn v1_to_v2(_settings: &mut Map<String, Value>) {}
+
+/// 설정 파일 내용을 지정한 형식으로 읽고 현재 버전으로 변환하여 해석
+///
+/// 읽을 수 없거나 허용 범위를 벗어난 항목은 기본값으로 대체하고 경고로 알림
+pub fn parse(content: &str, format: ConfigFormat) -> Result<(Settings, Vec<String>)> {
+    parse_value(format.parse(content)?)
+}
+
+/// 이미 읽어 둔 JSON 값을 설정으로 변환 (프로필 파일 안의 설정 등)
+pub fn parse_value(value: Value) -> Result<(Settings, Vec<String>)> {
+    let Value::Object(mut map) = value else {
+        return Err(anyhow::anyhow!("설정 파일 형식이 올바르지 않습니다"));
+    };
+    let mut warnings = upgrade(&mut map)?;
+
+    let mut settings = deserialize_tolerant(map, &mut warnings)?;
+    for error in settings.reset_invalid() {
+        warnings.push(format!("{}. 기본값을 사용합니다", error));
+    }
+    Ok((settings, warnings))
+}
+
+/// 항목 구조만 현재 버전으로 변환 (값은 해석하지 않음)
+pub fn upgrade(map: &mut Map<String, Value>) -> Result<Vec<String>> {
+    let mut warnings = Vec::new();
+
+    let version = match map.get("version") {
+        None => 1,
+        Some(value) => value
+            .as_u64()
+            .and_then(|v| u32::try_from(v).ok())
+            .filter(|v| *v >= 1)
+            .ok_or_else(|| anyhow::anyhow!("설정 파일 버전이 올바르지 않습니다: {}", value))?,
+    };
+
+    if version > CURRENT_VERSION {
+        warnings.push(format!(
+            "더 새로운 버전(v{})에서 저장한 설정입니다. 알 수 없는 항목은 무시되며 저장하면 사라집니다",
+            version
+        ));
+    }
+
+    for (index, migration) in MIGRATIONS.iter().enumerate().skip(version as usize - 1) {
+        migration(map);
+        log::info!("설정 변환: v{} → v{}", index + 1, index + 2);
+    }
+    map.insert("version".to_string(), CURRENT_VERSION.into());
+    Ok(warnings)
+}
+
+/// 전체를 해석할 수 없으면 항목별로 확인하여 읽을 수 없는 항목만 기본값으로 대체
+fn deserialize_tolerant(map: Map<String, Value>, warnings: &mut Vec<String>) -> Result<Settings> {
+    if let Ok(settings) = serde_json::from_value(Value::Object(map.clone())) {
+        return Ok(settings);
+    }
+
+    let Value::Object(mut merged) = serde_json::to_value(Settings::default())? else {
+        return Err(anyhow::anyhow!("기본 설정을 변환할 수 없습니다"));
+    };
+    for (key, value) in map {
+        let previous = merged.insert(key.clone(), value);
+        if let Err(e) = serde_json::from_value::<Settings>(Value::Object(merged.clone())) {
+            warnings.push(format!(
+                "'{}' 항목을 읽을 수 없어 기본값을 사용합니다: {}",
+                key, e
+            ));
+            match previous {
+                Some(previous) => merged.insert(key, previous),
+                None => merged.remove(&key),
+            };
+        }
+    }
+
+    Ok(serde_json::from_value(Value::Object(merged))?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::TimerMode;
+
+    #[test]
+    fn loads_unversioned_baseline_config() {
+        // 버전 필드가 생기기 전, 최초 형식의 설정 파일
+        let content = r#"{
+            "interval_seconds": 12.5,
+            "move_distance": 3,
+            "enable_sound": true,
+            "start_minimized": false,
+            "enable_timer": true,
+            "timer_minutes": 90
+        }"#;
+
+        let (settings, warnings) = parse(content, ConfigFormat::Json).unwrap();
+        assert!(warnings.is_empty());
+        assert_eq!(settings.version, CURRENT_VERSION);
+        assert_eq!(settings.interval_seconds, 12.5);
+        assert_eq!(settings.move_distance, 3);
+        assert_eq!(settings.timer_minutes, 90);
+        // 이후 추가된 항목은 기본값
+        assert_eq!(settings.timer_mode, TimerMode::Duration);
+        assert!(settings.enable_notifications);
+        assert_eq!(settings.resource_trigger.cooldown_seconds, 60);
+    }
+
+    #[test]
+    fn fills_missing_nested_fields() {
+        let content = r#"{"version": 2, "power": {"pause_on_battery": true}}"#;
+
+        let (settings, warnings) = parse(content, ConfigFormat::Json).unwrap();
+        assert!(warnings.is_empty());
+        assert!(settings.power.pause_on_battery);
+        assert_eq!(settings.power.sysfs_path, "/sys/class/power_supply");
+    }
+
+    #[test]
+    fn invalid_field_falls_back_to_default_with_warning() {
+        let content = r#"{"interval_seconds": "빠르게", "move_distance": 7}"#;
+
+        let (settings, warnings) = parse(content, ConfigFormat::Json).unwrap();
+        assert_eq!(
+            settings.interval_seconds,
+            Settings::default().interval_seconds
+        );
+        assert_eq!(settings.move_distance, 7);
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("interval_seconds"));
+    }
+
+    #[test]
+    fn newer_version_loads_with_warning() {
+        let content = r#"{"version": 99, "move_distance": 4, "future_option": true}"#;
+
+        let (settings, warnings) = parse(content, ConfigFormat::Json).unwrap();
+        assert_eq!(settings.move_distance, 4);
+        assert_eq!(warnings.len(), 1);
+    }
+
+    #[test]
+    fn unreadable_file_is_an_error() {
+        assert!(parse("{ 이건 JSON이 아님", ConfigFormat::Json).is_err());
+        assert!(parse("[1, 2, 3]", ConfigFormat::Json).is_err());
+        assert!(parse(r#"{"version": "two"}"#, ConfigFormat::Json).is_err());
+    }
+}
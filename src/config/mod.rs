@@ -1,3 +1,21 @@
+pub mod backup;
+pub mod bundle;
+pub mod cron;
+pub mod format;
+pub mod layers;
+pub mod location;
+pub mod migrate;
+pub mod profiles;
+pub mod schedule;
 pub mod settings;
+pub mod validate;
+pub mod watch;
 
-pub use settings::Settings;
+pub use cron::{CronSettings, CronTrigger};
+pub use format::ConfigFormat;
+pub use layers::Overrides;
+pub use location::ConfigLocation;
+pub use profiles::{ProfileStore, DEFAULT_PROFILE};
+pub use schedule::{Schedule, TimeRange};
+pub use settings::{ActivityKind, FullscreenAction, Settings, TimerMode};
+pub use validate::FieldError;
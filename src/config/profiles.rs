@@ -0,0 +1,392 @@
+use super::{backup, migrate, Settings};
+use anyhow::Result;
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// 설정 파일(config.json) 자체를 가리키는 프로필 이름
+pub const DEFAULT_PROFILE: &str = "기본";
+
+const PROFILES_FILE: &str = "profiles.json";
+
+/// 이름을 붙여 저장한 설정 묶음과 선택된 프로필
+///
+/// 기본 프로필은 기존 설정 파일을 그대로 사용하고, 나머지는 profiles.json에 저장
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ProfileStore {
+    /// 선택된 프로필 (None이면 기본 프로필)
+    active: Option<String>,
+    profiles: BTreeMap<String, Settings>,
+    #[serde(skip)]
+    path: PathBuf,
+    /// 프로필 파일을 읽지 못한 경우 파일을 덮어쓰지 않도록 변경을 거부
+    #[serde(skip)]
+    read_only: bool,
+}
+
+impl ProfileStore {
+    /// 설정 디렉토리에 저장된 프로필을 불러옴
+    pub fn load() -> Result<(Self, Vec<String>)> {
+        Self::load_from(&Self::path()?)
+    }
+
+    /// 지정한 프로필 파일을 불러오고, 프로필별로 읽지 못한 항목을 경고로 반환
+    pub fn load_from(path: &Path) -> Result<(Self, Vec<String>)> {
+        let mut store = Self {
+            path: path.to_path_buf(),
+            ..Self::default()
+        };
+        if !path.exists() {
+            return Ok((store, Vec::new()));
+        }
+
+        let (value, notice) = backup::load_with_recovery(path, |content| {
+            Ok(serde_json::from_str::<Value>(content)?)
+        })?;
+        let mut warnings: Vec<String> = notice.into_iter().collect();
+
+        if let Some(Value::Object(profiles)) = value.get("profiles") {
+            for (name, value) in profiles {
+                match migrate::parse_value(value.clone()) {
+                    Ok((settings, profile_warnings)) => {
+                        warnings.extend(
+                            profile_warnings
+                                .into_iter()
+                                .map(|w| format!("[{}] {}", name, w)),
+                        );
+                        store.profiles.insert(name.clone(), settings);
+                    }
+                    Err(e) => warnings.push(format!("[{}] 프로필을 읽을 수 없습니다: {}", name, e)),
+                }
+            }
+        }
+
+        store.active = value
+            .get("active")
+            .and_then(Value::as_str)
+            .map(str::to_string);
+        if let Some(active) = &store.active {
+            if !store.profiles.contains_key(active) {
+                warnings.push(format!(
+                    "선택된 프로필 '{}'을(를) 찾을 수 없어 기본 프로필을 사용합니다",
+                    active
+                ));
+                store.active = None;
+            }
+        }
+
+        Ok((store, warnings))
+    }
+
+    /// 프로필 파일을 읽지 못했을 때 사용하는 기본 프로필만 있는 저장소 (변경할 수 없음)
+    pub fn read_only() -> Self {
+        Self {
+            read_only: true,
+            ..Self::default()
+        }
+    }
+
+    pub fn save(&self) -> Result<()> {
+        self.ensure_writable()?;
+        backup::write_with_backup(&self.path, &serde_json::to_string_pretty(self)?)
+    }
+
+    /// 기본 프로필을 맨 앞에 둔 프로필 이름 목록
+    pub fn names(&self) -> Vec<String> {
+        std::iter::once(DEFAULT_PROFILE.to_string())
+            .chain(self.profiles.keys().cloned())
+            .collect()
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        name == DEFAULT_PROFILE || self.profiles.contains_key(name)
+    }
+
+    pub fn active_name(&self) -> &str {
+        self.active.as_deref().unwrap_or(DEFAULT_PROFILE)
+    }
+
+    /// 다음 실행에서도 사용할 프로필을 선택하고 저장
+    pub fn set_active(&mut self, name: &str) -> Result<()> {
+        self.ensure_writable()?;
+        self.ensure_exists(name)?;
+        self.active = (name != DEFAULT_PROFILE).then(|| name.to_string());
+        self.save()
+    }
+
+    /// 프로필의 저장된 설정
+    pub fn settings(&self, name: &str) -> Result<Settings> {
+        self.ensure_exists(name)?;
+        match self.profiles.get(name) {
+            Some(settings) => Ok(settings.clone()),
+            None => Settings::load(),
+        }
+    }
+
+    /// 요청한 프로필(없으면 선택된 프로필)의 이름과 설정, 경고를 반환
+    pub fn load_selected(
+        &self,
+        requested: Option<&str>,
+    ) -> Result<(String, Settings, Vec<String>)> {
+        let name = requested.unwrap_or(self.active_name());
+        self.ensure_exists(name)?;
+
+        match self.profiles.get(name) {
+            Some(settings) => Ok((name.to_string(), settings.clone(), Vec::new())),
+            None => {
+                let (settings, warnings) = Settings::load_with_warnings()?;
+                Ok((name.to_string(), settings, warnings))
+            }
+        }
+    }
+
+    /// 프로필에 설정을 저장 (기본 프로필은 설정 파일에 저장)
+    pub fn save_profile(&mut self, name: &str, settings: &Settings) -> Result<()> {
+        self.check_save(name, settings)?;
+        if name == DEFAULT_PROFILE {
+            return settings.save();
+        }
+
+        self.profiles.insert(name.to_string(), settings.clone());
+        self.save()
+    }
+
+    /// 저장하지 않고 `save_profile`이 받아들일 설정인지만 확인
+    pub fn check_save(&self, name: &str, settings: &Settings) -> Result<()> {
+        self.ensure_exists(name)?;
+        if name != DEFAULT_PROFILE {
+            self.ensure_writable()?;
+        }
+        settings.ensure_valid()
+    }
+
+    /// 지정한 설정으로 새 프로필을 만듦
+    pub fn create(&mut self, name: &str, settings: Settings) -> Result<String> {
+        self.ensure_writable()?;
+        let name = self.check_new_name(name)?;
+        self.profiles.insert(name.clone(), settings);
+        self.save()?;
+        Ok(name)
+    }
+
+    /// 기본 프로필을 제외한 프로필과 저장된 설정
+    pub fn named_profiles(&self) -> &BTreeMap<String, Settings> {
+        &self.profiles
+    }
+
+    /// 가져온 프로필을 추가하고, 같은 이름이 있으면 덮어씀
+    pub fn import(&mut self, profiles: &BTreeMap<String, Settings>) -> Result<()> {
+        self.ensure_writable()?;
+        for (name, settings) in profiles {
+            if name == DEFAULT_PROFILE {
+                return Err(anyhow::anyhow!("기본 프로필은 가져올 수 없습니다"));
+            }
+            settings
+                .ensure_valid()
+                .map_err(|e| anyhow::anyhow!("[{}] {}", name, e))?;
+        }
+        self.profiles.extend(profiles.clone());
+        self.save()
+    }
+
+    pub fn duplicate(&mut self, source: &str, name: &str) -> Result<String> {
+        let settings = self.settings(source)?;
+        self.create(name, settings)
+    }
+
+    /// 프로필 이름을 바꿈 (`default`는 기본 프로필의 저장된 설정으로, 시간표 참조 확인에 사용)
+    pub fn rename(&mut self, old: &str, new: &str, default: &Settings) -> Result<String> {
+        self.ensure_writable()?;
+        if old == DEFAULT_PROFILE {
+            return Err(anyhow::anyhow!("기본 프로필의 이름은 바꿀 수 없습니다"));
+        }
+        self.ensure_exists(old)?;
+        let new = self.check_new_name(new)?;
+        self.ensure_unreferenced(old, default)?;
+
+        if let Some(settings) = self.profiles.remove(old) {
+            self.profiles.insert(new.clone(), settings);
+        }
+        if self.active.as_deref() == Some(old) {
+            self.active = Some(new.clone());
+        }
+        self.save()?;
+        Ok(new)
+    }
+
+    /// 프로필을 삭제 (`default`는 `rename`과 같음)
+    pub fn delete(&mut self, name: &str, default: &Settings) -> Result<()> {
+        self.ensure_writable()?;
+        if name == DEFAULT_PROFILE {
+            return Err(anyhow::anyhow!("기본 프로필은 삭제할 수 없습니다"));
+        }
+        self.ensure_exists(name)?;
+        self.ensure_unreferenced(name, default)?;
+
+        self.profiles.remove(name);
+        if self.active.as_deref() == Some(name) {
+            self.active = None;
+        }
+        self.save()
+    }
+
+    fn ensure_writable(&self) -> Result<()> {
+        if self.read_only {
+            Err(anyhow::anyhow!(
+                "프로필 파일을 읽지 못해 프로필을 변경할 수 없습니다"
+            ))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// 시간표 구간에서 사용 중인 프로필은 이름을 바꾸거나 삭제하지 않음
+    fn ensure_unreferenced(&self, name: &str, default: &Settings) -> Result<()> {
+        let users: Vec<&str> = std::iter::once((DEFAULT_PROFILE, default))
+            .chain(
+                self.profiles
+                    .iter()
+                    .map(|(profile, settings)| (profile.as_str(), settings)),
+            )
+            .filter(|(_, settings)| settings.schedule.uses_profile(name))
+            .map(|(profile, _)| profile)
+            .collect();
+
+        if users.is_empty() {
+            Ok(())
+        } else {
+            Err(anyhow::anyhow!(
+                "시간표에서 사용 중인 프로필입니다 ({}). 시간표에서 먼저 지워 주세요",
+                users.join(", ")
+            ))
+        }
+    }
+
+    fn ensure_exists(&self, name: &str) -> Result<()> {
+        if self.contains(name) {
+            Ok(())
+        } else {
+            Err(anyhow::anyhow!("프로필을 찾을 수 없습니다: {}", name))
+        }
+    }
+
+    fn check_new_name(&self, name: &str) -> Result<String> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(anyhow::anyhow!("프로필 이름을 입력하세요"));
+        }
+        if self.contains(name) {
+            return Err(anyhow::anyhow!("이미 있는 프로필 이름입니다: {}", name));
+        }
+        Ok(name.to_string())
+    }
+
+    fn path() -> Result<PathBuf> {
+        Settings::sidecar_path(PROFILES_FILE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::TimeRange;
+    use crate::test_util::temp_dir;
+    use chrono::NaiveTime;
+    use std::fs;
+
+    fn load(path: &Path) -> ProfileStore {
+        let (store, warnings) = ProfileStore::load_from(path).unwrap();
+        assert!(warnings.is_empty(), "{:?}", warnings);
+        store
+    }
+
+    /// 월요일 구간 하나가 지정한 프로필을 사용하는 설정
+    fn scheduled_with(profile: &str) -> Settings {
+        let mut settings = Settings::default();
+        let mut range = TimeRange::new(
+            NaiveTime::from_hms_opt(22, 0, 0).unwrap(),
+            NaiveTime::from_hms_opt(6, 0, 0).unwrap(),
+        );
+        range.profile = Some(profile.to_string());
+        settings.schedule.days[0] = vec![range];
+        settings
+    }
+
+    #[test]
+    fn create_rename_delete_and_activate() {
+        let path = temp_dir("profiles-manage").join(PROFILES_FILE);
+        let default = Settings::default();
+        let mut store = load(&path);
+        assert_eq!(store.names(), [DEFAULT_PROFILE]);
+
+        let settings = Settings {
+            move_distance: 5,
+            ..Settings::default()
+        };
+        assert_eq!(store.create(" 회의 ", settings).unwrap(), "회의");
+        assert!(store.create("회의", Settings::default()).is_err());
+        assert!(store.create(DEFAULT_PROFILE, Settings::default()).is_err());
+        store.set_active("회의").unwrap();
+
+        let store = load(&path);
+        assert_eq!(store.active_name(), "회의");
+        assert_eq!(store.settings("회의").unwrap().move_distance, 5);
+
+        let mut store = store;
+        assert_eq!(store.rename("회의", "발표", &default).unwrap(), "발표");
+        let mut store = load(&path);
+        assert_eq!(store.names(), [DEFAULT_PROFILE, "발표"]);
+        assert_eq!(store.active_name(), "발표");
+
+        store.delete("발표", &default).unwrap();
+        assert!(store.delete(DEFAULT_PROFILE, &default).is_err());
+        let store = load(&path);
+        assert_eq!(store.names(), [DEFAULT_PROFILE]);
+        assert_eq!(store.active_name(), DEFAULT_PROFILE);
+    }
+
+    #[test]
+    fn scheduled_profile_cannot_be_renamed_or_deleted() {
+        let path = temp_dir("profiles-referenced").join(PROFILES_FILE);
+        let mut store = load(&path);
+        store.create("야간", Settings::default()).unwrap();
+        store.create("평일", scheduled_with("야간")).unwrap();
+
+        let default = Settings::default();
+        let error = store.delete("야간", &default).unwrap_err().to_string();
+        assert!(error.contains("(평일)"), "{}", error);
+        assert!(store.rename("야간", "밤", &default).is_err());
+
+        // 기본 프로필의 시간표도 확인
+        store.save_profile("평일", &Settings::default()).unwrap();
+        let error = store
+            .rename("야간", "밤", &scheduled_with("야간"))
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains(DEFAULT_PROFILE), "{}", error);
+
+        assert_eq!(store.rename("야간", "밤", &default).unwrap(), "밤");
+        assert_eq!(load(&path).names(), [DEFAULT_PROFILE, "밤", "평일"]);
+    }
+
+    #[test]
+    fn read_only_store_refuses_changes() {
+        let mut store = ProfileStore::read_only();
+        let default = Settings::default();
+        assert!(store.create("회의", Settings::default()).is_err());
+        assert!(store.set_active(DEFAULT_PROFILE).is_err());
+        assert!(store.import(&BTreeMap::new()).is_err());
+        assert!(store.save().is_err());
+        assert!(store.delete("회의", &default).is_err());
+        assert_eq!(store.names(), [DEFAULT_PROFILE]);
+    }
+
+    #[test]
+    fn unreadable_profiles_file_is_an_error() {
+        let path = temp_dir("profiles-corrupt").join(PROFILES_FILE);
+        fs::write(&path, "{ 잘린 파일").unwrap();
+        assert!(ProfileStore::load_from(&path).is_err());
+        assert_eq!(fs::read_to_string(&path).unwrap(), "{ 잘린 파일");
+    }
+}
@@ -0,0 +1,177 @@
+use chrono::{DateTime, Datelike, Local, NaiveTime, TimeZone};
+use serde::{Deserialize, Serialize};
+
+pub const WEEKDAY_LABELS: [&str; 7] = ["월", "화", "수", "목", "금", "토", "일"];
+
+/// 하루 중 동작할 시간 구간 (끝이 시작보다 이르면 자정을 넘어 다음 날까지)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TimeRange {
+    #[serde(with = "hhmm")]
+    pub start: NaiveTime,
+    #[serde(with = "hhmm")]
+    pub end: NaiveTime,
+    /// 이 구간에서 사용할 프로필 이름 (없으면 현재 설정 사용)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profile: Option<String>,
+}
+
+impl TimeRange {
+    pub fn new(start: NaiveTime, end: NaiveTime) -> Self {
+        Self {
+            start,
+            end,
+            profile: None,
+        }
+    }
+
+    fn crosses_midnight(&self) -> bool {
+        self.end <= self.start
+    }
+}
+
+/// 요일별 동작 시간표 (현지 시간 기준)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Schedule {
+    pub enabled: bool,
+    /// 월요일부터 일요일까지 요일별 시간 구간
+    pub days: [Vec<TimeRange>; 7],
+}
+
+impl Default for Schedule {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            days: Self::working_hours(),
+        }
+    }
+}
+
+impl Schedule {
+    /// 평일 09:00 ~ 18:00 시간표
+    pub fn working_hours() -> [Vec<TimeRange>; 7] {
+        let start = NaiveTime::from_hms_opt(9, 0, 0).unwrap();
+        let end = NaiveTime::from_hms_opt(18, 0, 0).unwrap();
+
+        let mut days: [Vec<TimeRange>; 7] = Default::default();
+        for day in days.iter_mut().take(5) {
+            day.push(TimeRange::new(start, end));
+        }
+        days
+    }
+
+    /// 주어진 시각에 해당하는 시간 구간
+    pub fn active_range<Tz: TimeZone>(&self, now: &DateTime<Tz>) -> Option<&TimeRange> {
+        let weekday = now.weekday().num_days_from_monday() as usize;
+        let time = now.time();
+
+        let today = self.days[weekday].iter().find(|range| {
+            if range.crosses_midnight() {
+                time >= range.start
+            } else {
+                range.start <= time && time < range.end
+            }
+        });
+
+        // 전날 시작해서 자정을 넘긴 구간
+        let yesterday = now.weekday().pred().num_days_from_monday() as usize;
+        today.or_else(|| {
+            self.days[yesterday]
+                .iter()
+                .find(|range| range.crosses_midnight() && time < range.end)
+        })
+    }
+
+    pub fn current_range(&self) -> Option<&TimeRange> {
+        self.active_range(&Local::now())
+    }
+
+    /// 지정한 프로필을 사용하는 구간이 있는지 (시간표를 끈 경우도 포함)
+    pub fn uses_profile(&self, name: &str) -> bool {
+        self.days
+            .iter()
+            .flatten()
+            .any(|range| range.profile.as_deref() == Some(name))
+    }
+}
+
+/// `NaiveTime`을 "HH:MM" 문자열로 저장
+pub(crate) mod hhmm {
+    use chrono::NaiveTime;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    const FORMAT: &str = "%H:%M";
+
+    pub fn serialize<S: Serializer>(time: &NaiveTime, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&time.format(FORMAT).to_string())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<NaiveTime, D::Error> {
+        let text = String::deserialize(deserializer)?;
+        NaiveTime::parse_from_str(&text, FORMAT)
+            .or_else(|_| NaiveTime::parse_from_str(&text, "%H:%M:%S"))
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    fn time(hour: u32, minute: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(hour, minute, 0).unwrap()
+    }
+
+    /// 2024-03-04는 월요일
+    fn at(day: u32, hour: u32, minute: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 3, day, hour, minute, 0).unwrap()
+    }
+
+    fn night_shift() -> Schedule {
+        let mut schedule = Schedule {
+            enabled: true,
+            days: Default::default(),
+        };
+        // 금요일 22:00 ~ 토요일 02:00
+        schedule.days[4].push(TimeRange::new(time(22, 0), time(2, 0)));
+        schedule
+    }
+
+    #[test]
+    fn working_hours_exclude_end_and_weekend() {
+        let schedule = Schedule {
+            enabled: true,
+            days: Schedule::working_hours(),
+        };
+        assert!(schedule.active_range(&at(4, 8, 59)).is_none());
+        assert!(schedule.active_range(&at(4, 9, 0)).is_some());
+        assert!(schedule.active_range(&at(4, 17, 59)).is_some());
+        assert!(schedule.active_range(&at(4, 18, 0)).is_none());
+        // 토요일
+        assert!(schedule.active_range(&at(9, 12, 0)).is_none());
+    }
+
+    #[test]
+    fn range_crossing_midnight_continues_next_day() {
+        let schedule = night_shift();
+        assert!(schedule.active_range(&at(8, 21, 59)).is_none());
+        assert!(schedule.active_range(&at(8, 22, 0)).is_some());
+        assert!(schedule.active_range(&at(8, 23, 59)).is_some());
+        // 토요일 새벽은 금요일 구간에 포함
+        assert!(schedule.active_range(&at(9, 0, 0)).is_some());
+        assert!(schedule.active_range(&at(9, 1, 59)).is_some());
+        assert!(schedule.active_range(&at(9, 2, 0)).is_none());
+        // 목요일 밤에서 이어지는 구간은 없음
+        assert!(schedule.active_range(&at(8, 1, 0)).is_none());
+    }
+
+    #[test]
+    fn sunday_range_wraps_to_monday() {
+        let mut schedule = night_shift();
+        schedule.days[6].push(TimeRange::new(time(23, 0), time(1, 0)));
+        assert!(schedule.active_range(&at(10, 23, 30)).is_some());
+        assert!(schedule.active_range(&at(11, 0, 30)).is_some());
+        assert!(schedule.active_range(&at(11, 1, 0)).is_none());
+    }
+}
@@ -1,10 +1,19 @@
+use super::schedule::hhmm;
+use super::{backup, migrate};
+use super::{ConfigFormat, ConfigLocation, CronSettings, Schedule};
+use crate::timer::{CycleSettings, ExpiryAction};
 use anyhow::Result;
+use chrono::NaiveTime;
 use serde::{Deserialize, Serialize};
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct Settings {
+    /// 설정 파일 형식 버전 (이전 버전 파일은 불러올 때 변환)
+    pub version: u32,
+
     pub interval_seconds: f64,
     pub move_distance: i32,
     pub enable_sound: bool,
@@ -13,53 +22,347 @@ pub struct Settings {
     // 타이머 설정
     pub enable_timer: bool,
     pub timer_minutes: u32,
+    /// 만료 몇 분 전에 경고할지 (0이면 경고 안 함)
+    pub warn_before_minutes: u32,
+    /// 재시작 시 이전 타이머를 묻지 않고 이어서 진행
+    pub auto_resume_timer: bool,
+    /// 절전(대기 모드) 시간도 타이머 경과 시간에 포함 (종료 시각 모드는 항상 포함)
+    pub count_sleep_toward_timer: bool,
+    pub timer_mode: TimerMode,
+    #[serde(with = "hhmm")]
+    pub timer_until: NaiveTime,
+    pub expiry_actions: Vec<ExpiryAction>,
+
+    // 동작 방식 설정
+    pub activity_kind: ActivityKind,
+    pub fullscreen: FullscreenSettings,
+
+    // 알림 설정
+    pub enable_notifications: bool,
+
+    // 프로세스 감시 설정
+    pub process_watch: ProcessWatchSettings,
+
+    // 시스템 부하 트리거 설정
+    pub resource_trigger: ResourceTriggerSettings,
+
+    // 전원 설정
+    pub power: PowerSettings,
+
+    // 화면 잠금 시 일시정지
+    pub pause_when_locked: bool,
+
+    // 요일별 동작 시간표
+    pub schedule: Schedule,
+
+    // cron 표현식 트리거
+    pub cron: CronSettings,
+
+    // 작업/휴식 주기
+    pub cycle: CycleSettings,
+}
+
+fn default_timer_until() -> NaiveTime {
+    NaiveTime::from_hms_opt(18, 0, 0).unwrap()
+}
+
+fn default_warn_before_minutes() -> u32 {
+    5
+}
+
+fn default_expiry_actions() -> Vec<ExpiryAction> {
+    vec![ExpiryAction::Stop]
+}
+
+/// 타이머 종료 기준
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum TimerMode {
+    /// 지정한 시간(분) 동안
+    #[default]
+    Duration,
+    /// 지정한 시각(HH:MM)까지
+    Until,
+}
+
+/// 자리비움 방지를 위해 수행하는 동작의 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ActivityKind {
+    /// 마우스 포인터를 살짝 움직였다가 되돌림
+    #[default]
+    MouseMove,
+    /// 화면 변화 없이 화면보호기/유휴 타이머만 초기화
+    ScreenSaverReset,
+}
+
+impl ActivityKind {
+    pub const ALL: [ActivityKind; 2] = [ActivityKind::MouseMove, ActivityKind::ScreenSaverReset];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ActivityKind::MouseMove => "마우스 이동",
+            ActivityKind::ScreenSaverReset => "화면보호기 초기화",
+        }
+    }
+}
+
+/// 전체 화면 창이 활성화되어 있을 때의 처리 방식
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FullscreenAction {
+    /// 평소와 같은 동작 수행
+    Keep,
+    /// 화면에 보이지 않는 동작으로 대체
+    NonVisual,
+    /// 동작 건너뛰기
+    Skip,
+}
+
+impl FullscreenAction {
+    pub const ALL: [FullscreenAction; 3] = [
+        FullscreenAction::Keep,
+        FullscreenAction::NonVisual,
+        FullscreenAction::Skip,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            FullscreenAction::Keep => "그대로 수행",
+            FullscreenAction::NonVisual => "보이지 않는 동작으로 대체",
+            FullscreenAction::Skip => "건너뛰기",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FullscreenSettings {
+    pub detect: bool,
+    pub mouse_move: FullscreenAction,
+    pub screensaver_reset: FullscreenAction,
+}
+
+impl Default for FullscreenSettings {
+    fn default() -> Self {
+        Self {
+            detect: true,
+            mouse_move: FullscreenAction::NonVisual,
+            screensaver_reset: FullscreenAction::Keep,
+        }
+    }
+}
+
+impl FullscreenSettings {
+    pub fn action_for(&self, kind: ActivityKind) -> FullscreenAction {
+        match kind {
+            ActivityKind::MouseMove => self.mouse_move,
+            ActivityKind::ScreenSaverReset => self.screensaver_reset,
+        }
+    }
+
+    pub fn action_for_mut(&mut self, kind: ActivityKind) -> &mut FullscreenAction {
+        match kind {
+            ActivityKind::MouseMove => &mut self.mouse_move,
+            ActivityKind::ScreenSaverReset => &mut self.screensaver_reset,
+        }
+    }
+}
+
+/// 지정한 프로세스가 실행되는 동안에만 동작하도록 하는 설정
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ProcessWatchSettings {
+    pub enabled: bool,
+    /// PID 또는 프로세스 이름 패턴
+    pub target: String,
+}
+
+/// CPU/디스크/네트워크 부하가 높은 동안 자동으로 동작하도록 하는 설정
+///
+/// 임계값이 0이면 해당 항목은 확인하지 않음
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ResourceTriggerSettings {
+    pub enabled: bool,
+    pub cpu_percent: f64,
+    pub disk_mb_per_sec: f64,
+    pub net_mb_per_sec: f64,
+    /// 평균을 계산할 구간 (초)
+    pub window_seconds: u32,
+    /// 부하가 낮아진 뒤 중지하기까지 대기 시간 (초)
+    pub cooldown_seconds: u32,
+}
+
+impl Default for ResourceTriggerSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            cpu_percent: 30.0,
+            disk_mb_per_sec: 5.0,
+            net_mb_per_sec: 1.0,
+            window_seconds: 10,
+            cooldown_seconds: 60,
+        }
+    }
+}
+
+/// 배터리 사용 시 동작 방식
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PowerSettings {
+    /// 배터리로 동작 중이면 일시정지
+    pub pause_on_battery: bool,
+    /// 배터리 잔량이 이 값(%) 미만이면 일시정지 (0이면 사용 안 함)
+    pub pause_below_percent: u8,
+    /// 배터리로 동작 중일 때 사용할 동작 간격 (0이면 사용 안 함)
+    pub battery_interval_seconds: f64,
+    /// 전원 정보를 읽을 sysfs 디렉토리
+    pub sysfs_path: String,
+}
+
+impl Default for PowerSettings {
+    fn default() -> Self {
+        Self {
+            pause_on_battery: false,
+            pause_below_percent: 0,
+            battery_interval_seconds: 0.0,
+            sysfs_path: "/sys/class/power_supply".to_string(),
+        }
+    }
 }
 
 impl Default for Settings {
     fn default() -> Self {
         Self {
+            version: migrate::CURRENT_VERSION,
             interval_seconds: 30.0,
             move_distance: 10,
             enable_sound: false,
             start_minimized: false,
             enable_timer: false,
             timer_minutes: 60,
+            warn_before_minutes: default_warn_before_minutes(),
+            auto_resume_timer: false,
+            count_sleep_toward_timer: true,
+            timer_mode: TimerMode::default(),
+            timer_until: default_timer_until(),
+            expiry_actions: default_expiry_actions(),
+            activity_kind: ActivityKind::default(),
+            fullscreen: FullscreenSettings::default(),
+            enable_notifications: true,
+            process_watch: ProcessWatchSettings::default(),
+            resource_trigger: ResourceTriggerSettings::default(),
+            power: PowerSettings::default(),
+            pause_when_locked: true,
+            schedule: Schedule::default(),
+            cron: CronSettings::default(),
+            cycle: CycleSettings::default(),
         }
     }
 }
 
 impl Settings {
+    /// 설정을 불러오고, 일부 항목을 읽지 못한 경우 경고를 로그로 남김
     pub fn load() -> Result<Self> {
-        let config_path = Self::get_config_path()?;
-
-        if config_path.exists() {
-            let content = fs::read_to_string(config_path)?;
-            let settings: Settings = serde_json::from_str(&content)?;
-            Ok(settings)
-        } else {
-            Ok(Settings::default())
+        let (settings, warnings) = Self::load_with_warnings()?;
+        for warning in warnings {
+            log::warn!("{}", warning);
         }
+        Ok(settings)
     }
 
-    pub fn save(&self) -> Result<()> {
-        let config_path = Self::get_config_path()?;
+    /// 설정을 불러오면서 이전 버전 변환, 읽지 못한 항목 등 사용자에게 알릴 내용을 함께 반환
+    ///
+    /// 파일 자체를 해석할 수 없는 경우에만 오류를 반환
+    pub fn load_with_warnings() -> Result<(Self, Vec<String>)> {
+        let candidates = Self::existing_config_paths()?;
 
-        // 디렉토리 생성
-        if let Some(parent) = config_path.parent() {
-            fs::create_dir_all(parent)?;
+        match candidates.first() {
+            Some(config_path) => {
+                // 쓰다가 잘린 파일 등은 가장 최근의 올바른 백업으로 대체
+                let format = ConfigFormat::from_path(config_path).unwrap_or(ConfigFormat::Json);
+                let ((settings, mut warnings), notice) =
+                    backup::load_with_recovery(config_path, |content| {
+                        migrate::parse(content, format)
+                    })?;
+                warnings.extend(notice);
+                if candidates.len() > 1 {
+                    warnings.push(format!(
+                        "설정 파일이 여러 개 있어 {}을(를) 사용합니다",
+                        config_path.display()
+                    ));
+                }
+                Ok((settings, warnings))
+            }
+            None => Ok((Settings::default(), Vec::new())),
         }
+    }
+
+    /// 지정한 파일에서 설정을 불러옴 (형식은 확장자로 판단)
+    pub fn load_from(path: &Path) -> Result<(Self, Vec<String>)> {
+        let content = fs::read_to_string(path)?;
+        let format = ConfigFormat::from_path(path).unwrap_or(ConfigFormat::Json);
+        migrate::parse(&content, format)
+    }
+
+    /// 불러온 파일과 같은 형식으로 저장
+    pub fn save(&self) -> Result<()> {
+        self.save_to(&Self::config_path()?)
+    }
 
-        let content = serde_json::to_string_pretty(self)?;
-        fs::write(config_path, content)?;
+    /// 지정한 파일에 저장 (형식은 확장자로 판단)
+    pub fn save_to(&self, path: &Path) -> Result<()> {
+        self.ensure_valid()?;
 
-        Ok(())
+        let format = ConfigFormat::from_path(path).unwrap_or(ConfigFormat::Json);
+        let content = format.serialize(self)?;
+        backup::write_with_backup(path, &content)
     }
 
-    fn get_config_path() -> Result<PathBuf> {
-        let mut path = dirs::config_dir()
-            .ok_or_else(|| anyhow::anyhow!("설정 디렉토리를 찾을 수 없습니다"))?;
-        path.push("auto-mouse");
-        path.push("config.json");
-        Ok(path)
+    /// 설정 파일과 함께 쓰는 부가 파일(타이머 기록 등)의 경로
+    pub fn sidecar_path(name: &str) -> Result<PathBuf> {
+        Ok(ConfigLocation::current()?.sidecar(name))
+    }
+
+    /// 사용 중인 설정 파일
+    ///
+    /// 지정한 파일이 없으면 config.toml, config.yaml, config.json 순으로 찾고, 없으면 config.json
+    pub fn config_path() -> Result<PathBuf> {
+        if let Some(path) = ConfigLocation::current()?.explicit_file() {
+            return Ok(path.to_path_buf());
+        }
+        match Self::existing_config_paths()?.into_iter().next() {
+            Some(path) => Ok(path),
+            None => Ok(Self::config_path_for(ConfigFormat::Json)?),
+        }
+    }
+
+    /// 지정한 형식으로 저장할 때의 설정 파일 경로 (지정한 파일은 확장자만 바꿈)
+    pub fn config_path_for(format: ConfigFormat) -> Result<PathBuf> {
+        let location = ConfigLocation::current()?;
+        Ok(match location.explicit_file() {
+            Some(path) => path.with_extension(format.extension()),
+            None => location
+                .dir()
+                .join(format!("config.{}", format.extension())),
+        })
+    }
+
+    fn existing_config_paths() -> Result<Vec<PathBuf>> {
+        let location = ConfigLocation::current()?;
+        if let Some(path) = location.explicit_file() {
+            return Ok(path
+                .exists()
+                .then(|| path.to_path_buf())
+                .into_iter()
+                .collect());
+        }
+
+        let dir = location.dir();
+        let paths = ConfigFormat::ALL
+            .iter()
+            .flat_map(|format| format.extensions())
+            .map(|ext| dir.join(format!("config.{}", ext)))
+            .filter(|path| path.exists())
+            .collect();
+        Ok(paths)
     }
 }
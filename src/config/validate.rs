@@ -0,0 +1,268 @@
+use super::Settings;
+use std::fmt;
+
+/// 허용 범위를 벗어난 설정 항목
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldError {
+    /// 설정 파일에서의 항목 경로 (예: `power.battery_interval_seconds`)
+    pub field: &'static str,
+    pub label: &'static str,
+    pub value: f64,
+    pub min: f64,
+    pub max: f64,
+}
+
+impl FieldError {
+    /// "1~300" 형식의 허용 범위
+    pub fn allowed(&self) -> String {
+        format!("{}~{}", self.min, self.max)
+    }
+}
+
+impl fmt::Display for FieldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}({}) 값 {}이(가) 허용 범위({})를 벗어났습니다",
+            self.label,
+            self.field,
+            self.value,
+            self.allowed()
+        )
+    }
+}
+
+/// 범위를 확인할 숫자 항목
+struct NumericField {
+    field: &'static str,
+    label: &'static str,
+    min: f64,
+    max: f64,
+    get: fn(&Settings) -> f64,
+    set: fn(&mut Settings, f64),
+}
+
+/// 설정 화면의 입력 범위와 같게 유지
+const NUMERIC_FIELDS: &[NumericField] = &[
+    NumericField {
+        field: "interval_seconds",
+        label: "동작 간격 (초)",
+        min: 1.0,
+        max: 300.0,
+        get: |s| s.interval_seconds,
+        set: |s, v| s.interval_seconds = v,
+    },
+    NumericField {
+        field: "move_distance",
+        label: "이동 거리 (픽셀)",
+        min: 1.0,
+        max: 100.0,
+        get: |s| s.move_distance as f64,
+        set: |s, v| s.move_distance = v as i32,
+    },
+    NumericField {
+        field: "timer_minutes",
+        label: "타이머 시간 (분)",
+        min: 1.0,
+        max: 480.0,
+        get: |s| s.timer_minutes as f64,
+        set: |s, v| s.timer_minutes = v as u32,
+    },
+    NumericField {
+        field: "warn_before_minutes",
+        label: "만료 경고 (분 전)",
+        min: 0.0,
+        max: 120.0,
+        get: |s| s.warn_before_minutes as f64,
+        set: |s, v| s.warn_before_minutes = v as u32,
+    },
+    NumericField {
+        field: "resource_trigger.cpu_percent",
+        label: "CPU (%)",
+        min: 0.0,
+        max: 100.0,
+        get: |s| s.resource_trigger.cpu_percent,
+        set: |s, v| s.resource_trigger.cpu_percent = v,
+    },
+    NumericField {
+        field: "resource_trigger.disk_mb_per_sec",
+        label: "디스크 (MB/s)",
+        min: 0.0,
+        max: 500.0,
+        get: |s| s.resource_trigger.disk_mb_per_sec,
+        set: |s, v| s.resource_trigger.disk_mb_per_sec = v,
+    },
+    NumericField {
+        field: "resource_trigger.net_mb_per_sec",
+        label: "네트워크 (MB/s)",
+        min: 0.0,
+        max: 100.0,
+        get: |s| s.resource_trigger.net_mb_per_sec,
+        set: |s, v| s.resource_trigger.net_mb_per_sec = v,
+    },
+    NumericField {
+        field: "resource_trigger.window_seconds",
+        label: "평균 구간 (초)",
+        min: 1.0,
+        max: 300.0,
+        get: |s| s.resource_trigger.window_seconds as f64,
+        set: |s, v| s.resource_trigger.window_seconds = v as u32,
+    },
+    NumericField {
+        field: "resource_trigger.cooldown_seconds",
+        label: "중지 대기 (초)",
+        min: 0.0,
+        max: 1800.0,
+        get: |s| s.resource_trigger.cooldown_seconds as f64,
+        set: |s, v| s.resource_trigger.cooldown_seconds = v as u32,
+    },
+    NumericField {
+        field: "power.pause_below_percent",
+        label: "배터리 잔량 기준 (%)",
+        min: 0.0,
+        max: 100.0,
+        get: |s| s.power.pause_below_percent as f64,
+        set: |s, v| s.power.pause_below_percent = v as u8,
+    },
+    NumericField {
+        field: "power.battery_interval_seconds",
+        label: "배터리 사용 시 간격 (초)",
+        min: 0.0,
+        max: 600.0,
+        get: |s| s.power.battery_interval_seconds,
+        set: |s, v| s.power.battery_interval_seconds = v,
+    },
+    NumericField {
+        field: "cycle.work_minutes",
+        label: "작업 시간 (분)",
+        min: 1.0,
+        max: 240.0,
+        get: |s| s.cycle.work_minutes as f64,
+        set: |s, v| s.cycle.work_minutes = v as u32,
+    },
+    NumericField {
+        field: "cycle.short_break_minutes",
+        label: "짧은 휴식 (분)",
+        min: 1.0,
+        max: 240.0,
+        get: |s| s.cycle.short_break_minutes as f64,
+        set: |s, v| s.cycle.short_break_minutes = v as u32,
+    },
+    NumericField {
+        field: "cycle.long_break_minutes",
+        label: "긴 휴식 (분)",
+        min: 1.0,
+        max: 240.0,
+        get: |s| s.cycle.long_break_minutes as f64,
+        set: |s, v| s.cycle.long_break_minutes = v as u32,
+    },
+    NumericField {
+        field: "cycle.long_break_every",
+        label: "긴 휴식 주기 (회)",
+        min: 0.0,
+        max: 20.0,
+        get: |s| s.cycle.long_break_every as f64,
+        set: |s, v| s.cycle.long_break_every = v as u32,
+    },
+];
+
+impl Settings {
+    /// 모든 숫자 항목이 허용 범위 안에 있는지 확인
+    pub fn validate(&self) -> Result<(), Vec<FieldError>> {
+        let errors: Vec<FieldError> = NUMERIC_FIELDS
+            .iter()
+            .filter_map(|field| {
+                let value = (field.get)(self);
+                // NaN도 범위를 벗어난 것으로 처리
+                (!(field.min..=field.max).contains(&value)).then_some(FieldError {
+                    field: field.field,
+                    label: field.label,
+                    value,
+                    min: field.min,
+                    max: field.max,
+                })
+            })
+            .collect();
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// 허용 범위를 벗어난 항목이 있으면 모두 나열한 오류를 반환
+    pub fn ensure_valid(&self) -> anyhow::Result<()> {
+        self.validate().map_err(|errors| {
+            let errors: Vec<String> = errors.iter().map(ToString::to_string).collect();
+            anyhow::anyhow!("{}", errors.join(", "))
+        })
+    }
+
+    /// 허용 범위를 벗어난 항목을 기본값으로 되돌리고 해당 항목을 반환
+    pub fn reset_invalid(&mut self) -> Vec<FieldError> {
+        let Err(errors) = self.validate() else {
+            return Vec::new();
+        };
+
+        let defaults = Settings::default();
+        for error in &errors {
+            if let Some(field) = NUMERIC_FIELDS.iter().find(|f| f.field == error.field) {
+                (field.set)(self, (field.get)(&defaults));
+            }
+        }
+        errors
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_settings_are_valid() {
+        assert_eq!(Settings::default().validate(), Ok(()));
+    }
+
+    #[test]
+    fn reports_each_out_of_range_field() {
+        let settings = Settings {
+            interval_seconds: -5.0,
+            move_distance: 100_000,
+            ..Settings::default()
+        };
+
+        let errors = settings.validate().unwrap_err();
+        let fields: Vec<_> = errors.iter().map(|e| e.field).collect();
+        assert_eq!(fields, ["interval_seconds", "move_distance"]);
+        assert_eq!(errors[1].allowed(), "1~100");
+        assert_eq!(
+            errors[0].to_string(),
+            "동작 간격 (초)(interval_seconds) 값 -5이(가) 허용 범위(1~300)를 벗어났습니다"
+        );
+    }
+
+    #[test]
+    fn nan_is_invalid() {
+        let mut settings = Settings::default();
+        settings.power.battery_interval_seconds = f64::NAN;
+        assert!(settings.validate().is_err());
+    }
+
+    #[test]
+    fn reset_invalid_restores_defaults_only_for_bad_fields() {
+        let mut settings = Settings {
+            interval_seconds: 0.0,
+            move_distance: 42,
+            ..Settings::default()
+        };
+        settings.cycle.work_minutes = 0;
+
+        let errors = settings.reset_invalid();
+        assert_eq!(errors.len(), 2);
+        assert_eq!(settings.interval_seconds, 30.0);
+        assert_eq!(settings.cycle.work_minutes, 25);
+        assert_eq!(settings.move_distance, 42);
+        assert_eq!(settings.validate(), Ok(()));
+    }
+}
@@ -0,0 +1,223 @@
+use super::{ConfigFormat, ConfigLocation};
+use anyhow::Result;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+// 편집기나 스크립트가 여러 번 나눠 쓰는 경우 마지막 쓰기 후 이만큼 조용해지면 한 번만 알림
+const DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// 설정 디렉토리를 감시하여 설정 파일(config.json/toml/yaml 또는 지정한 파일)이 바뀌면 알림
+///
+/// 파일을 새로 만들어 이름을 바꾸는 방식으로 저장하는 편집기도 있으므로 파일이 아닌 디렉토리를 감시함
+pub struct ConfigWatcher {
+    changed: Arc<AtomicBool>,
+}
+
+impl ConfigWatcher {
+    /// 감시를 시작하고 변경이 확정되면 `wake`를 호출하여 앱을 깨움
+    pub fn spawn<F>(location: &ConfigLocation, wake: F) -> Result<Self>
+    where
+        F: Fn() + Send + 'static,
+    {
+        let changed = Arc::new(AtomicBool::new(false));
+        let dir = location.dir();
+        let file_name = location
+            .explicit_file()
+            .and_then(Path::file_name)
+            .and_then(|name| name.to_str())
+            .map(str::to_string);
+
+        #[cfg(target_os = "linux")]
+        {
+            std::fs::create_dir_all(&dir)?;
+            inotify::watch(&dir, file_name, Arc::clone(&changed), wake)?;
+            Ok(Self { changed })
+        }
+
+        #[cfg(not(target_os = "linux"))]
+        {
+            let _ = (dir, file_name, wake, changed);
+            Err(anyhow::anyhow!("지원되지 않는 플랫폼입니다"))
+        }
+    }
+
+    /// 마지막 확인 이후 설정 파일이 바뀌었는지 여부 (확인하면 초기화)
+    pub fn take_change(&self) -> bool {
+        self.changed.swap(false, Ordering::Relaxed)
+    }
+}
+
+/// 감시 대상인 설정 파일 이름인지 (백업, 임시 파일 등은 제외)
+///
+/// `file_name`을 지정하면 그 파일만 감시
+fn is_watched(name: &str, file_name: Option<&str>) -> bool {
+    match file_name {
+        Some(file_name) => name == file_name,
+        None => name.starts_with("config.") && ConfigFormat::from_path(Path::new(name)).is_some(),
+    }
+}
+
+#[cfg(target_os = "linux")]
+mod inotify {
+    use super::{is_watched, DEBOUNCE};
+    use anyhow::Result;
+    use std::ffi::CString;
+    use std::io;
+    use std::os::unix::ffi::OsStrExt;
+    use std::path::Path;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+
+    // struct inotify_event의 고정 길이 부분 (wd, mask, cookie, len)
+    const EVENT_HEADER: usize = 16;
+
+    pub fn watch<F>(
+        dir: &Path,
+        file_name: Option<String>,
+        changed: Arc<AtomicBool>,
+        wake: F,
+    ) -> Result<()>
+    where
+        F: Fn() + Send + 'static,
+    {
+        let path = CString::new(dir.as_os_str().as_bytes())?;
+        let mask = libc::IN_CLOSE_WRITE
+            | libc::IN_MOVED_TO
+            | libc::IN_MOVED_FROM
+            | libc::IN_CREATE
+            | libc::IN_DELETE;
+
+        let fd = unsafe { libc::inotify_init1(libc::IN_CLOEXEC) };
+        if fd < 0 {
+            return Err(io::Error::last_os_error().into());
+        }
+        if unsafe { libc::inotify_add_watch(fd, path.as_ptr(), mask) } < 0 {
+            let error = io::Error::last_os_error();
+            unsafe { libc::close(fd) };
+            return Err(error.into());
+        }
+
+        std::thread::spawn(move || {
+            if let Err(e) = run(fd, file_name.as_deref(), &changed, &wake) {
+                log::warn!("설정 파일 감시 중단: {}", e);
+            }
+            unsafe { libc::close(fd) };
+        });
+        Ok(())
+    }
+
+    fn run<F: Fn()>(
+        fd: i32,
+        file_name: Option<&str>,
+        changed: &AtomicBool,
+        wake: &F,
+    ) -> Result<()> {
+        loop {
+            if !read_events(fd, file_name)? {
+                continue;
+            }
+
+            // 짧은 간격으로 이어지는 쓰기는 한 번의 변경으로 묶음
+            while wait_readable(fd)? {
+                read_events(fd, file_name)?;
+            }
+
+            changed.store(true, Ordering::Relaxed);
+            wake();
+        }
+    }
+
+    /// 대기 중인 이벤트를 읽고 설정 파일에 대한 이벤트가 있었는지 반환
+    fn read_events(fd: i32, file_name: Option<&str>) -> Result<bool> {
+        let mut buffer = [0u8; 4096];
+        let read = unsafe { libc::read(fd, buffer.as_mut_ptr().cast(), buffer.len()) };
+        if read < 0 {
+            let error = io::Error::last_os_error();
+            if error.kind() == io::ErrorKind::Interrupted {
+                return Ok(false);
+            }
+            return Err(error.into());
+        }
+
+        let mut relevant = false;
+        let mut events = &buffer[..read as usize];
+        while events.len() >= EVENT_HEADER {
+            let len = u32::from_ne_bytes([events[12], events[13], events[14], events[15]]) as usize;
+            let end = (EVENT_HEADER + len).min(events.len());
+            let name = &events[EVENT_HEADER..end];
+            let name = &name[..name.iter().position(|&b| b == 0).unwrap_or(name.len())];
+            relevant |= std::str::from_utf8(name).is_ok_and(|name| is_watched(name, file_name));
+            events = &events[end..];
+        }
+        Ok(relevant)
+    }
+
+    /// 조용해지기 전에 새 이벤트가 들어오면 true
+    fn wait_readable(fd: i32) -> Result<bool> {
+        let mut poll_fd = libc::pollfd {
+            fd,
+            events: libc::POLLIN,
+            revents: 0,
+        };
+        let timeout = DEBOUNCE.as_millis() as i32;
+        match unsafe { libc::poll(&mut poll_fd, 1, timeout) } {
+            n if n > 0 => Ok(true),
+            0 => Ok(false),
+            _ => {
+                let error = io::Error::last_os_error();
+                if error.kind() == io::ErrorKind::Interrupted {
+                    Ok(true)
+                } else {
+                    Err(error.into())
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_config_files_are_watched() {
+        assert!(is_watched("config.json", None));
+        assert!(is_watched("config.yml", None));
+        assert!(!is_watched("config.json.bak", None));
+        assert!(!is_watched(".config.json.swp", None));
+        assert!(!is_watched("profiles.json", None));
+        assert!(!is_watched("timer_state.json", None));
+
+        assert!(is_watched("work.toml", Some("work.toml")));
+        assert!(!is_watched("config.json", Some("work.toml")));
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn reports_one_change_for_rapid_writes() {
+        let dir = crate::test_util::temp_dir("watch");
+        let (sender, receiver) = std::sync::mpsc::channel();
+        let watcher = ConfigWatcher::spawn(&ConfigLocation::Standard(dir.clone()), move || {
+            let _ = sender.send(());
+        })
+        .unwrap();
+
+        std::fs::write(dir.join("timer_state.json"), "{}").unwrap();
+        for i in 0..5 {
+            std::fs::write(
+                dir.join("config.json"),
+                format!("{{\"move_distance\": {}}}", i),
+            )
+            .unwrap();
+        }
+
+        receiver.recv_timeout(Duration::from_secs(5)).unwrap();
+        assert!(watcher.take_change());
+        assert!(!watcher.take_change());
+        assert!(receiver.recv_timeout(DEBOUNCE * 2).is_err());
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
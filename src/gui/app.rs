@@ -1,14 +1,65 @@
-use crate::config::Settings;
+use super::widgets;
+use crate::cli::CliArgs;
+use crate::config::bundle::{Bundle, ExportOptions, ImportPreview};
+use crate::config::cron::{CronEvaluator, CronEvent, CronWindows};
+use crate::config::schedule::WEEKDAY_LABELS;
+use crate::config::watch::ConfigWatcher;
+use crate::config::{
+    ActivityKind, ConfigLocation, CronTrigger, FullscreenAction, Overrides, ProfileStore, Schedule,
+    Settings, TimeRange, TimerMode, DEFAULT_PROFILE,
+};
+use crate::ipc::{IpcCommand, IpcRequest, IpcServer};
+use crate::monitor::resources::ResourceReadings;
+use crate::monitor::{
+    fullscreen, read_power_state, PowerState, ProcessEvent, ProcessTarget, ProcessWatcher,
+    ResourceMonitor, ResourceThresholds, ResourceTrigger, SessionLockWatcher, TriggerEvent,
+};
 use crate::mouse::MouseController;
-use crate::timer::SimpleTimer;
+use crate::notify;
+use crate::timer::expiry;
+use crate::timer::persist::{self, SavedTimer};
+use crate::timer::simple_timer::TimerState;
+use crate::timer::snooze::{self, SnoozeStats};
+use crate::timer::suspend::format_duration;
+use crate::timer::{
+    next_deadline, Clock, ExpiryAction, SimpleTimer, SuspendDetector, SystemClock, WorkCycle,
+};
+use chrono::{DateTime, Local, NaiveTime};
 use egui::{Context, Ui};
+use std::path::Path;
 use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
 
+const MONITOR_POLL_INTERVAL: Duration = Duration::from_secs(1);
+const BYTES_PER_MB: f64 = 1024.0 * 1024.0;
+const SNOOZE_CHOICES: [(u32, &str); 2] = [(15, "+15분"), (60, "+1시간")];
+// 설정 파일을 다시 불러왔다는 안내를 표시하는 시간
+const RELOAD_NOTICE: Duration = Duration::from_secs(10);
+
+/// 동작을 시작시킨 주체 (자동 중지는 자신이 시작한 경우에만 수행)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum StartSource {
+    Manual,
+    ProcessWatch,
+    ResourceLoad,
+    Schedule,
+    Cron,
+}
+
+impl StartSource {
+    /// 이 주체의 자동 중지가 `current`로 시작된 동작을 멈출 수 있는지 (자신이 시작한 경우만)
+    fn may_stop(self, current: Option<StartSource>) -> bool {
+        current == Some(self)
+    }
+}
+
 pub struct AutoMouseApp {
+    // 타이머, 동작 스레드, 상태 표시가 함께 사용하는 시계
+    clock: Arc<dyn Clock>,
     mouse_controller: Arc<Mutex<MouseController>>,
     settings: Settings,
     is_active: bool,
+    start_source: Option<StartSource>,
     last_activity: Instant,
     status_text: String,
     is_collapsed: bool,
@@ -16,13 +67,88 @@ pub struct AutoMouseApp {
 
     // 타이머 추가
     timer: SimpleTimer,
-    
+
     // 최소화 처리를 위한 플래그
     should_minimize: bool,
+
+    // 자동 중지 사유 (비활성 상태에서 표시)
+    stop_reason: Option<String>,
+
+    // 프로세스 감시
+    process_watcher: Option<ProcessWatcher>,
+
+    // 시스템 부하 감시
+    resource_monitor: ResourceMonitor,
+    resource_trigger: ResourceTrigger,
+    last_monitor_poll: Option<Instant>,
+
+    // 전원 상태 및 일시정지 사유
+    power_state: Option<PowerState>,
+    pause_reason: Option<String>,
+
+    // 화면 잠금 감시
+    lock_watcher: SessionLockWatcher,
+
+    // 사용자가 일시정지했는지, 앱이 타이머를 멈춰 두었는지 여부
+    user_paused: bool,
+    timer_held: bool,
+
+    // 현재 시간표 구간 안에 있는지 여부
+    in_schedule_range: bool,
+
+    // cron 트리거 평가
+    cron_evaluator: CronEvaluator<SystemClock>,
+    cron_windows: CronWindows,
+
+    // 타이머 만료 동작 결과
+    expiry_errors: Vec<String>,
+    quit_requested: bool,
+
+    // 만료 전 경고 및 타이머 연장
+    expiry_warned: bool,
+    snooze_stats: SnoozeStats,
+
+    // 다른 프로세스(CLI)에서 오는 명령
+    ipc_server: Option<IpcServer>,
+
+    // 작업/휴식 주기
+    work_cycle: WorkCycle,
+
+    // 재시작 전 타이머 (이어서 진행할지 묻는 중) 및 관련 안내
+    pending_resume: Option<SavedTimer>,
+    resume_notice: Option<String>,
+
+    // 설정 파일을 불러올 때 발생한 문제
+    config_warnings: Vec<String>,
+
+    // 이름 붙인 설정 프로필과 현재 사용 중인 프로필
+    profiles: ProfileStore,
+    profile_name: String,
+    profile_name_input: String,
+    profile_error: Option<String>,
+
+    // 환경 변수와 명령줄에서 덮어쓴 값, 덮어쓰기 전 파일(프로필)의 설정
+    overrides: Overrides,
+    file_settings: Settings,
+
+    // 절전 감지 (마지막으로 감지한 시각과 절전 시간)
+    suspend_detector: SuspendDetector,
+    last_suspend: Option<(DateTime<Local>, Duration)>,
+
+    // 설정 파일 변경 감지 (앱이 마지막으로 읽거나 저장한 내용과 다시 불러온 결과)
+    config_watcher: Option<ConfigWatcher>,
+    config_snapshot: Option<String>,
+    config_reload: Option<(Instant, Result<String, String>)>,
+
+    // 설정 내보내기/가져오기 (파일 경로, 내보낼 항목, 가져오기 전 미리보기, 결과)
+    bundle_path: String,
+    bundle_options: ExportOptions,
+    bundle_preview: Option<(Bundle, ImportPreview)>,
+    bundle_result: Option<Result<String, String>>,
 }
 
 impl AutoMouseApp {
-    pub fn new(cc: &eframe::CreationContext<'_>) -> Self {
+    pub fn new(cc: &eframe::CreationContext<'_>, args: CliArgs) -> Self {
         // egui가 한글을 지원하도록 폰트를 설정합니다.
         let mut fonts = egui::FontDefinitions::default();
 
@@ -49,240 +175,1890 @@ impl AutoMouseApp {
         // egui 컨텍스트에 폰트 설정을 적용합니다.
         cc.egui_ctx.set_fonts(fonts);
 
-        let settings = Settings::load().unwrap_or_default();
-        let mouse_controller = Arc::new(Mutex::new(MouseController::new()));
-        let timer = SimpleTimer::new(settings.timer_minutes);
+        // 창 시스템이 자신의 X 오류 처리기를 설치한 뒤에 설치하여, 감지용 연결이 아닌 오류는 그쪽으로 넘어가게 함
+        fullscreen::install_error_handler();
+
+        let (profiles, mut config_warnings) = ProfileStore::load().unwrap_or_else(|e| {
+            let warning = format!(
+                "프로필 파일을 읽을 수 없어 기본 프로필만 사용합니다 ({}). 프로필은 변경할 수 없습니다",
+                e
+            );
+            (ProfileStore::read_only(), vec![warning])
+        });
+        let requested = args.profile.as_deref().filter(|name| {
+            let exists = profiles.contains(name);
+            if !exists {
+                config_warnings.push(format!("프로필을 찾을 수 없습니다: {}", name));
+            }
+            exists
+        });
+
+        // 설정 파일을 읽지 못해도 조용히 초기화하지 않고 화면에 알림
+        let (profile_name, file_settings) = match profiles.load_selected(requested) {
+            Ok((name, settings, warnings)) => {
+                config_warnings.extend(warnings);
+                (name, settings)
+            }
+            Err(e) => {
+                config_warnings.push(format!(
+                    "설정 파일을 읽을 수 없어 기본값으로 시작했습니다 ({}). 설정을 저장하면 기존 파일은 backups 디렉토리에 보관됩니다",
+                    e
+                ));
+                (DEFAULT_PROFILE.to_string(), Settings::default())
+            }
+        };
+
+        // 환경 변수와 명령줄 값을 설정 파일 위에 덮어씀
+        let (overrides, env_warnings) = Overrides::from_env(args.overrides());
+        let (settings, override_warnings) = overrides.apply(&file_settings);
+        config_warnings.extend(env_warnings);
+        config_warnings.extend(override_warnings);
+        for warning in &config_warnings {
+            log::warn!("{}", warning);
+        }
+        let clock: Arc<dyn Clock> = Arc::new(SystemClock);
+        let mouse_controller =
+            Arc::new(Mutex::new(MouseController::with_clock(Arc::clone(&clock))));
+        let timer = SimpleTimer::with_clock(settings.timer_minutes, Arc::clone(&clock));
+        let resource_monitor = ResourceMonitor::new(Duration::from_secs(
+            settings.resource_trigger.window_seconds as u64,
+        ));
+
+        // 요청이 들어오면 창이 가려져 있어도 바로 처리하도록 화면 갱신 요청
+        let egui_ctx = cc.egui_ctx.clone();
+        let ipc_server = IpcServer::start(move || egui_ctx.request_repaint())
+            .map_err(|e| log::warn!("명령 수신 소켓을 열 수 없습니다: {}", e))
+            .ok();
+
+        let egui_ctx = cc.egui_ctx.clone();
+        let config_watcher = ConfigLocation::current()
+            .and_then(|location| {
+                ConfigWatcher::spawn(&location, move || egui_ctx.request_repaint())
+            })
+            .map_err(|e| log::warn!("설정 파일 변경을 감지할 수 없습니다: {}", e))
+            .ok();
 
-        Self {
+        let mut app = Self {
             mouse_controller,
             should_minimize: settings.start_minimized,
             settings,
             is_active: false,
-            last_activity: Instant::now(),
+            start_source: None,
+            last_activity: clock.instant(),
             status_text: "대기 중".to_string(),
             is_collapsed: true,
             last_collapsed_state: true,
             timer,
-        }
+            stop_reason: None,
+            process_watcher: None,
+            resource_monitor,
+            resource_trigger: ResourceTrigger::new(Arc::clone(&clock)),
+            last_monitor_poll: None,
+            power_state: None,
+            pause_reason: None,
+            lock_watcher: SessionLockWatcher::spawn(),
+            user_paused: false,
+            timer_held: false,
+            in_schedule_range: false,
+            cron_evaluator: CronEvaluator::new(SystemClock),
+            cron_windows: CronWindows::default(),
+            expiry_errors: Vec::new(),
+            quit_requested: false,
+            expiry_warned: false,
+            snooze_stats: snooze::stats().unwrap_or_default(),
+            ipc_server,
+            work_cycle: WorkCycle::with_clock(Arc::clone(&clock)),
+            pending_resume: None,
+            resume_notice: None,
+            suspend_detector: SuspendDetector::new(clock.as_ref()),
+            last_suspend: None,
+            config_warnings,
+            profiles,
+            profile_name,
+            profile_name_input: String::new(),
+            profile_error: None,
+            overrides,
+            file_settings,
+            config_watcher,
+            config_snapshot: read_config_file(),
+            config_reload: None,
+            bundle_path: default_bundle_path(),
+            bundle_options: ExportOptions::default(),
+            bundle_preview: None,
+            bundle_result: None,
+            clock,
+        };
+        app.restore_saved_timer();
+        app
     }
 
-    fn update_status(&mut self) {
-        // 타이머 만료 체크
-        if self.settings.enable_timer && self.timer.is_expired() && self.is_active {
-            self.is_active = false;
-            if let Ok(mut controller) = self.mouse_controller.lock() {
-                controller.stop();
+    /// 이전 실행에서 저장된 타이머를 확인하여 이어서 진행하거나 사용자에게 물음
+    fn restore_saved_timer(&mut self) {
+        let saved = match persist::load() {
+            Ok(Some(saved)) => saved,
+            Ok(None) => return,
+            Err(e) => {
+                log::warn!("저장된 타이머 상태를 읽을 수 없습니다: {}", e);
+                self.clear_saved_timer();
+                return;
             }
-            self.status_text = "타이머 만료로 자동 중지됨".to_string();
+        };
+
+        if !self.settings.enable_timer {
+            self.clear_saved_timer();
             return;
         }
 
-        if self.is_active {
-            let elapsed = self.last_activity.elapsed();
-            let mut status = format!("활성화됨 - 마지막 동작: {}초 전", elapsed.as_secs());
-
-            // 타이머 정보 추가
-            if self.settings.enable_timer {
-                let remaining = self.timer.get_remaining_seconds();
-                let minutes = remaining / 60;
-                let seconds = remaining % 60;
-                status.push_str(&format!(" ({}분 {}초 남음)", minutes, seconds));
+        match (&saved, saved.remaining(self.clock.now())) {
+            (SavedTimer::Running { deadline }, None) => {
+                // 꺼져 있는 동안 만료된 경우 만료 동작은 수행하지 않고 알리기만 함
+                self.resume_notice = Some(format!(
+                    "이전 타이머는 {}에 이미 만료되었습니다",
+                    deadline.format("%m-%d %H:%M")
+                ));
+                self.clear_saved_timer();
             }
+            _ if self.settings.auto_resume_timer => self.resume_saved_timer(saved),
+            _ => self.pending_resume = Some(saved),
+        }
+    }
 
-            self.status_text = status;
+    /// 저장된 타이머의 남은 시간으로 동작 시작
+    fn resume_saved_timer(&mut self, saved: SavedTimer) {
+        self.pending_resume = None;
+        let Some(remaining) = saved.remaining(self.clock.now()) else {
+            self.resume_notice = Some("이전 타이머가 그 사이 만료되었습니다".to_string());
+            self.clear_saved_timer();
+            return;
+        };
+
+        self.start(StartSource::Manual);
+        match saved {
+            SavedTimer::Running { deadline } => self.timer.start_until(deadline),
+            SavedTimer::Paused { .. } => self.timer.start_for(remaining),
+        }
+        self.save_timer_state();
+        self.resume_notice = Some(format!(
+            "이전 타이머를 이어서 진행합니다 ({})",
+            saved.describe()
+        ));
+        log::info!("이전 타이머 재개: {}", saved.describe());
+    }
+
+    /// 현재 타이머 상태를 설정 디렉토리에 저장 (비활성 상태면 삭제)
+    fn save_timer_state(&self) {
+        let saved = if self.is_active && self.settings.enable_timer {
+            SavedTimer::capture(&self.timer)
         } else {
-            self.status_text = "비활성화됨".to_string();
+            None
+        };
+        if let Err(e) = persist::save(saved.as_ref()) {
+            log::warn!("타이머 상태 저장 실패: {}", e);
         }
     }
-}
 
-impl eframe::App for AutoMouseApp {
-    fn update(&mut self, ctx: &Context, _frame: &mut eframe::Frame) {
-        // 첫 번째 업데이트에서 시작 시 최소화 처리
-        if self.should_minimize {
-            ctx.send_viewport_cmd(egui::viewport::ViewportCommand::Minimized(true));
-            self.should_minimize = false;
+    fn clear_saved_timer(&self) {
+        if let Err(e) = persist::save(None) {
+            log::warn!("저장된 타이머 상태 삭제 실패: {}", e);
         }
+    }
 
-        self.update_status();
+    fn start(&mut self, source: StartSource) {
+        self.start_with(source, self.settings.clone());
+    }
 
-        egui::CentralPanel::default().show(ctx, |ui| {
-            ui.heading("Stay Awake...");
-            ui.separator();
+    /// 지정한 설정(프로필)으로 동작 시작
+    fn start_with(&mut self, source: StartSource, settings: Settings) {
+        if self.is_active {
+            return;
+        }
 
-            // 상태 표시
-            ui.label(format!("상태: {}", self.status_text));
-            ui.separator();
+        self.is_active = true;
+        self.start_source = Some(source);
+        self.last_activity = self.clock.instant();
+        self.stop_reason = None;
+        self.expiry_errors.clear();
+        self.pending_resume = None;
 
-            self.collapsible_ui(ui, ctx);
+        // 마우스 컨트롤러 시작
+        if let Ok(mut controller) = self.mouse_controller.lock() {
+            controller.start(settings);
+        }
 
-            // 창 크기를 콘텐츠에 맞게 자동 조정
-            ui.allocate_space(ui.available_size());
-        });
+        // 타이머 시작 (설정이 활성화된 경우)
+        if self.settings.enable_timer {
+            match self.settings.timer_mode {
+                TimerMode::Duration => {
+                    self.timer.set_duration(self.settings.timer_minutes);
+                    self.timer.start();
+                }
+                TimerMode::Until => {
+                    let deadline = next_deadline(&self.clock.now(), self.settings.timer_until);
+                    self.timer.start_until(deadline);
+                }
+            }
+        }
 
-        // 축소/확대 상태가 변경될 때 창 크기 자동 조정
-        if self.should_resize() {
-            ctx.send_viewport_cmd(egui::viewport::ViewportCommand::Resizable(true));
+        // 작업/휴식 주기 시작 (첫 구간은 작업)
+        if self.settings.cycle.enabled {
+            self.work_cycle.start(&self.settings.cycle);
+        }
+        self.save_timer_state();
+    }
 
-            // 상태에 따른 적절한 창 크기 설정
-            let target_size = if self.is_collapsed {
-                egui::vec2(300.0, 200.0) // 축소 시 크기
-            } else {
-                egui::vec2(300.0, 450.0) // 확대 시 크기
-            };
+    fn stop(&mut self) {
+        self.is_active = false;
+        self.start_source = None;
 
-            ctx.send_viewport_cmd(egui::viewport::ViewportCommand::InnerSize(target_size));
-            self.last_collapsed_state = self.is_collapsed;
+        // 마우스 컨트롤러 중지
+        if let Ok(mut controller) = self.mouse_controller.lock() {
+            controller.stop();
         }
 
-        // 자동 업데이트
-        ctx.request_repaint_after(Duration::from_secs(1));
+        // 타이머 중지
+        self.timer.stop();
+        self.work_cycle.stop();
+        self.timer_held = false;
+        self.user_paused = false;
+        self.save_timer_state();
     }
-}
 
-impl AutoMouseApp {
-    fn collapsible_ui(&mut self, ui: &mut Ui, _ctx: &Context) {
-        // Controls는 항상 표시 (맨 위로 이동)
-        ui.heading("Controls");
-        self.control_ui(ui);
+    /// 사용자가 아닌 조건에 의해 중지될 때 사유를 남기고 알림
+    fn stop_with_reason(&mut self, reason: String) {
+        self.stop();
 
-        ui.separator();
+        if self.settings.enable_notifications {
+            notify::notify("Stay awake 중지", &reason, self.settings.enable_sound);
+        }
+        self.stop_reason = Some(reason);
+    }
 
-        // 확대/축소 버튼
-        let button_text = if self.is_collapsed {
-            "▼ 확대"
+    fn poll_monitors(&mut self) {
+        if let Some(last_poll) = self.last_monitor_poll {
+            if last_poll.elapsed() < MONITOR_POLL_INTERVAL {
+                return;
+            }
+        }
+        self.last_monitor_poll = Some(Instant::now());
+
+        self.poll_process_watch();
+        self.poll_resources();
+        self.poll_power();
+        self.poll_schedule();
+        self.poll_cron();
+        self.refresh_pause();
+    }
+
+    fn poll_cron(&mut self) {
+        self.cron_windows.update(&self.settings.cron.triggers);
+        let windows = if self.settings.cron.enabled {
+            self.cron_windows.windows()
         } else {
-            "▲ 축소"
+            &[]
         };
-        if ui.button(button_text).clicked() {
-            self.is_collapsed = !self.is_collapsed;
+
+        match self.cron_evaluator.poll(windows) {
+            Some(CronEvent::Start) => {
+                log::info!("cron 트리거로 시작");
+                self.start(StartSource::Cron);
+            }
+            Some(CronEvent::Stop) if StartSource::Cron.may_stop(self.start_source) => {
+                self.stop_with_reason("cron 트리거로 자동 중지됨".to_string());
+            }
+            _ => {}
         }
+    }
 
-        if !self.is_collapsed {
-            // Expanded view: show other sections
-            ui.separator();
+    /// 시간표 구간에 들어오면 시작하고, 벗어나면 시간표로 시작한 경우에만 중지
+    fn poll_schedule(&mut self) {
+        let range = if self.settings.schedule.enabled {
+            self.settings.schedule.current_range().cloned()
+        } else {
+            None
+        };
 
-            ui.heading("Settings");
-            self.settings_ui(ui);
+        match (range, self.in_schedule_range) {
+            (Some(range), false) => {
+                self.in_schedule_range = true;
+                log::info!(
+                    "시간표 구간 시작: {} ~ {}",
+                    range.start.format("%H:%M"),
+                    range.end.format("%H:%M")
+                );
 
-            ui.separator();
+                let settings = range
+                    .profile
+                    .as_deref()
+                    .and_then(|name| self.profile_settings(name))
+                    .unwrap_or_else(|| self.settings.clone());
+                self.start_with(StartSource::Schedule, settings);
+            }
+            (None, true) => {
+                self.in_schedule_range = false;
+                if StartSource::Schedule.may_stop(self.start_source) {
+                    self.stop_with_reason("시간표 구간이 끝나 자동 중지됨".to_string());
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// 이름으로 프로필 설정을 찾음
+    fn profile_settings(&self, name: &str) -> Option<Settings> {
+        self.profiles
+            .settings(name)
+            .map_err(|e| log::warn!("{}, 현재 설정을 사용합니다", e))
+            .ok()
+            .map(|settings| self.with_overrides(&settings))
+    }
 
-            ui.heading("Statistics");
-            self.stats_ui(ui);
+    /// 환경 변수와 명령줄 값을 덮어쓴 설정 (문제가 있으면 로그로 남김)
+    fn with_overrides(&self, settings: &Settings) -> Settings {
+        let (settings, warnings) = self.overrides.apply(settings);
+        for warning in warnings {
+            log::warn!("{}", warning);
         }
+        settings
+    }
 
-        // 창 크기를 콘텐츠에 맞게 자동 조정
-        ui.ctx().request_repaint();
+    /// 저장할 설정 (화면에서 바꾸지 않은 덮어쓴 값은 파일의 값으로 되돌림)
+    fn settings_to_save(&self) -> Settings {
+        self.overrides.restore(&self.settings, &self.file_settings)
     }
 
-    fn settings_ui(&mut self, ui: &mut Ui) {
-        ui.horizontal(|ui| {
-            ui.label("동작 간격 (초):");
-            ui.add(egui::Slider::new(
-                &mut self.settings.interval_seconds,
-                1.0..=300.0,
-            ));
-        });
+    /// 다른 프로필로 전환 (저장하지 않은 변경은 버림)
+    fn switch_profile(&mut self, name: &str) {
+        let file_settings = match self.profiles.settings(name) {
+            Ok(settings) => settings,
+            Err(e) => {
+                self.profile_error = Some(e.to_string());
+                return;
+            }
+        };
 
-        ui.horizontal(|ui| {
-            ui.label("이동 거리 (픽셀):");
-            ui.add(egui::Slider::new(&mut self.settings.move_distance, 1..=100));
-        });
+        if let Err(e) = self.profiles.set_active(name) {
+            log::warn!("선택된 프로필 저장 실패: {}", e);
+        }
+        self.settings = self.with_overrides(&file_settings);
+        self.file_settings = file_settings;
+        self.profile_name = name.to_string();
+        self.profile_error = None;
+        self.apply_timer_settings();
+        log::info!("프로필 전환: {}", name);
+    }
 
-        ui.checkbox(&mut self.settings.enable_sound, "소리 알림");
-        ui.checkbox(&mut self.settings.start_minimized, "시작 시 최소화");
+    /// 기본 프로필의 저장된 설정 (프로필 참조 확인용)
+    fn default_profile_settings(&self) -> anyhow::Result<Settings> {
+        if self.profile_name == DEFAULT_PROFILE {
+            Ok(self.file_settings.clone())
+        } else {
+            self.profiles.settings(DEFAULT_PROFILE)
+        }
+    }
 
-        ui.separator();
+    /// 프로필 만들기/복제/이름 바꾸기/삭제 결과를 반영
+    fn apply_profile_change(&mut self, result: anyhow::Result<Option<String>>) {
+        match result {
+            Ok(Some(name)) => {
+                self.profile_name_input.clear();
+                self.switch_profile(&name);
+            }
+            Ok(None) => {
+                self.profile_error = None;
+            }
+            Err(e) => self.profile_error = Some(e.to_string()),
+        }
+    }
 
-        // 타이머 설정 UI
-        ui.checkbox(&mut self.settings.enable_timer, "자동 타이머 사용");
+    fn poll_process_watch(&mut self) {
+        let target = if self.settings.process_watch.enabled {
+            ProcessTarget::parse(&self.settings.process_watch.target)
+        } else {
+            None
+        };
 
-        if self.settings.enable_timer {
-            ui.horizontal(|ui| {
-                ui.label("타이머 시간 (분):");
-                if ui
-                    .add(egui::Slider::new(&mut self.settings.timer_minutes, 1..=480).text("분"))
-                    .changed()
-                {
-                    self.timer.set_duration(self.settings.timer_minutes);
-                }
-            });
+        // 설정이 바뀌면 감시 대상을 새로 만듦
+        if self.process_watcher.as_ref().map(|w| w.target()) != target.as_ref() {
+            self.process_watcher = target.map(ProcessWatcher::new);
+        }
 
-            // 타이머 상태 표시
-            match self.timer.get_state() {
-                crate::timer::simple_timer::TimerState::Stopped => {
-                    ui.label("타이머: 비활성화");
-                }
-                crate::timer::simple_timer::TimerState::Running => {
-                    let remaining = self.timer.get_remaining_seconds();
-                    let minutes = remaining / 60;
-                    let seconds = remaining % 60;
-                    ui.label(format!("남은 시간: {}분 {}초", minutes, seconds));
-                }
-                crate::timer::simple_timer::TimerState::Expired => {
-                    ui.colored_label(egui::Color32::RED, "타이머: 시간 만료");
+        let Some(watcher) = self.process_watcher.as_mut() else {
+            return;
+        };
+        let description = watcher.target().describe();
+
+        match watcher.poll() {
+            Some(ProcessEvent::Appeared(pid)) => {
+                log::info!("감시 대상 프로세스 감지: {} (PID {})", description, pid);
+                self.start(StartSource::ProcessWatch);
+            }
+            Some(ProcessEvent::Exited(pid)) => {
+                log::info!("감시 대상 프로세스 종료: {} (PID {})", description, pid);
+                // 직접 또는 다른 트리거로 시작한 동작은 그대로 둠
+                if StartSource::ProcessWatch.may_stop(self.start_source) {
+                    self.stop_with_reason(format!(
+                        "감시 중인 프로세스 {}(PID {})가 종료되어 중지됨",
+                        description, pid
+                    ));
                 }
             }
+            None => {}
         }
+    }
 
-        if ui.button("설정 저장").clicked() {
-            if let Err(e) = self.settings.save() {
-                self.status_text = format!("설정 저장 실패: {}", e);
-            } else {
-                self.status_text = "설정이 저장되었습니다".to_string();
+    fn poll_power(&mut self) {
+        let sysfs_path = Path::new(&self.settings.power.sysfs_path);
+        self.power_state = match read_power_state(sysfs_path) {
+            Ok(state) => Some(state),
+            Err(e) => {
+                log::debug!("전원 상태 확인 실패: {}", e);
+                None
             }
-        }
+        };
     }
 
-    fn control_ui(&mut self, ui: &mut Ui) {
-        ui.horizontal(|ui| {
-            if ui
-                .button(if self.is_active { "중지" } else { "시작" })
-                .clicked()
-            {
-                self.is_active = !self.is_active;
-                self.last_activity = Instant::now();
+    /// 현재 전원 상태에서 일시정지해야 하는 경우 그 사유
+    fn power_pause_reason(&self) -> Option<String> {
+        self.power_state
+            .as_ref()?
+            .pause_reason(&self.settings.power)
+    }
 
-                if self.is_active {
-                    // 마우스 컨트롤러 시작
-                    if let Ok(mut controller) = self.mouse_controller.lock() {
-                        controller.start(self.settings.clone());
-                    }
+    /// 배터리 사용 중에 적용할 동작 간격
+    fn power_interval(&self) -> Option<f64> {
+        let on_battery = self.power_state.as_ref().is_some_and(|s| s.on_battery());
+        let interval = self.settings.power.battery_interval_seconds;
+        (on_battery && interval > 0.0).then_some(interval)
+    }
 
-                    // 타이머 시작 (설정이 활성화된 경우)
-                    if self.settings.enable_timer {
-                        self.timer.start();
-                    }
-                } else {
-                    // 마우스 컨트롤러 중지
-                    if let Ok(mut controller) = self.mouse_controller.lock() {
-                        controller.stop();
-                    }
+    /// 일시정지 조건을 다시 계산하여 컨트롤러에 반영
+    fn refresh_pause(&mut self) {
+        let locked = self.settings.pause_when_locked && self.lock_watcher.is_locked();
+        let reason = if self.user_paused {
+            Some("사용자가 일시정지함".to_string())
+        } else if locked {
+            Some("화면 잠김".to_string())
+        } else if self.work_cycle.is_running() && self.work_cycle.phase().is_break() {
+            Some(format!("{} 시간", self.work_cycle.phase().label()))
+        } else {
+            self.power_pause_reason()
+        };
+        let interval = self.power_interval();
 
-                    // 타이머 중지
-                    self.timer.stop();
-                }
-            }
+        // 사용자 일시정지 또는 화면 잠금 중에는 타이머도 멈춤 (종료 시각 모드는 재개해도 같은 시각에 만료)
+        let hold_timer = self.user_paused || locked;
+        // 휴식 구간은 화면이 잠겨 있어도 계속 흘러가야 작업으로 돌아올 수 있음
+        let hold_cycle = self.user_paused || (locked && !self.work_cycle.phase().is_break());
+        if hold_cycle {
+            self.work_cycle.pause();
+        } else {
+            self.work_cycle.resume();
+        }
+        if hold_timer && self.timer.get_state() == TimerState::Running {
+            self.timer.pause();
+            self.timer_held = true;
+            self.save_timer_state();
+        } else if !hold_timer && self.timer_held {
+            self.timer.resume();
+            self.timer_held = false;
+            self.save_timer_state();
+        }
 
-            if ui.button("테스트 동작").clicked() {
-                if let Ok(mut controller) = self.mouse_controller.lock() {
-                    controller.move_mouse(self.settings.move_distance);
-                }
+        if let Ok(controller) = self.mouse_controller.lock() {
+            controller.set_paused(reason.is_some());
+            controller.set_interval_override(interval);
+        }
+
+        if reason != self.pause_reason {
+            match &reason {
+                Some(reason) => log::info!("일시정지: {}", reason),
+                None => log::info!("일시정지 해제"),
             }
-        });
+            self.pause_reason = reason;
+        }
     }
 
-    fn stats_ui(&mut self, ui: &mut Ui) {
-        if let Ok(controller) = self.mouse_controller.lock() {
-            ui.label(format!("총 동작 횟수: {}", controller.get_total_moves()));
-            ui.label(format!(
-                "마지막 동작 시간: {}",
-                controller.get_last_move_time().format("%H:%M:%S")
-            ));
+    fn poll_resources(&mut self) {
+        let trigger = self.settings.resource_trigger.clone();
+
+        // 통계 표시를 위해 트리거 사용 여부와 관계없이 측정
+        self.resource_monitor
+            .set_window(Duration::from_secs(trigger.window_seconds.max(1) as u64));
+        if let Err(e) = self.resource_monitor.sample() {
+            log::debug!("시스템 부하 측정 실패: {}", e);
+            return;
+        }
+
+        if !trigger.enabled {
+            self.resource_trigger.reset();
+            return;
+        }
+
+        let Some(readings) = self.resource_monitor.readings() else {
+            return;
+        };
+        let thresholds = ResourceThresholds {
+            cpu_percent: trigger.cpu_percent,
+            disk_bytes_per_sec: trigger.disk_mb_per_sec * BYTES_PER_MB,
+            net_bytes_per_sec: trigger.net_mb_per_sec * BYTES_PER_MB,
+        };
+        let cooldown = Duration::from_secs(trigger.cooldown_seconds as u64);
+
+        match self
+            .resource_trigger
+            .evaluate(&readings, &thresholds, cooldown)
+        {
+            Some(TriggerEvent::Activate) => {
+                log::info!("시스템 부하 감지로 시작: {:?}", readings);
+                self.start(StartSource::ResourceLoad);
+            }
+            Some(TriggerEvent::Deactivate)
+                if StartSource::ResourceLoad.may_stop(self.start_source) =>
+            {
+                self.stop_with_reason("시스템 부하가 낮아져 자동 중지됨".to_string());
+            }
+            _ => {}
         }
     }
 
-    fn should_resize(&self) -> bool {
-        self.is_collapsed != self.last_collapsed_state
+    /// 설정된 만료 동작을 순서대로 수행하고 실패한 동작을 기록
+    fn run_expiry_actions(&mut self) {
+        self.expiry_errors.clear();
+
+        for action in self.settings.expiry_actions.clone() {
+            let result = match &action {
+                ExpiryAction::Stop => {
+                    self.stop_with_reason("타이머 만료로 자동 중지됨".to_string());
+                    Ok(())
+                }
+                ExpiryAction::Quit => {
+                    self.quit_requested = true;
+                    Ok(())
+                }
+                other => expiry::execute(other),
+            };
+
+            match result {
+                Ok(()) => log::info!("만료 동작 수행: {}", action.label()),
+                Err(e) => {
+                    log::warn!("만료 동작 실패 ({}): {}", action.label(), e);
+                    self.expiry_errors
+                        .push(format!("{} 실패: {}", action.label(), e));
+                }
+            }
+        }
+    }
+
+    /// 작업/휴식 구간이 끝나면 다음 구간으로 넘어가고 알림
+    fn poll_cycle(&mut self) {
+        let Some(phase) = self.work_cycle.poll(&self.settings.cycle) else {
+            return;
+        };
+
+        let body = if phase.is_break() {
+            format!(
+                "작업 {}회 완료, {}분간 쉬세요",
+                self.work_cycle.completed(),
+                self.settings.cycle.minutes_for(phase)
+            )
+        } else {
+            format!("{}번째 작업을 시작합니다", self.work_cycle.completed() + 1)
+        };
+        log::info!("{} 시작: {}", phase.label(), body);
+        if self.settings.enable_notifications {
+            notify::notify(
+                &format!("Stay awake {} 시작", phase.label()),
+                &body,
+                self.settings.enable_sound,
+            );
+        }
+        self.refresh_pause();
+    }
+
+    /// "작업 2회차, 12분 3초 남음" 형식의 주기 정보
+    fn cycle_text(&self) -> String {
+        let remaining = self.work_cycle.remaining_seconds();
+        let phase = self.work_cycle.phase();
+        let label = if phase.is_break() {
+            phase.label().to_string()
+        } else {
+            format!("작업 {}회차", self.work_cycle.completed() + 1)
+        };
+        format!("{}, {}분 {}초 남음", label, remaining / 60, remaining % 60)
+    }
+
+    /// 남은 시간이 경고 기준 이하로 처음 내려가면 알림 (연장 등으로 다시 늘어나면 초기화)
+    fn check_expiry_warning(&mut self) {
+        let threshold = self.settings.warn_before_minutes as u64 * 60;
+        if threshold == 0 || self.timer.get_state() != TimerState::Running {
+            return;
+        }
+
+        let remaining = self.timer.get_remaining_seconds();
+        if remaining > threshold {
+            self.expiry_warned = false;
+        } else if !self.expiry_warned {
+            self.expiry_warned = true;
+            if self.settings.enable_notifications {
+                notify::notify(
+                    "Stay awake 타이머 만료 임박",
+                    &format!(
+                        "{} - 창에서 시간을 연장할 수 있습니다",
+                        self.timer.remaining_text()
+                    ),
+                    self.settings.enable_sound,
+                );
+            }
+        }
+    }
+
+    /// 타이머를 초기화하지 않고 만료 시점만 뒤로 미룸
+    fn snooze(&mut self, minutes: u32, source: &str) -> Result<String, String> {
+        if minutes == 0 {
+            return Err("연장할 시간은 1분 이상이어야 합니다".to_string());
+        }
+        if !self.is_active
+            || !matches!(
+                self.timer.get_state(),
+                TimerState::Running | TimerState::Paused
+            )
+        {
+            return Err("동작 중인 타이머가 없습니다".to_string());
+        }
+
+        self.timer.extend(Duration::from_secs(minutes as u64 * 60));
+        self.expiry_warned = false;
+        self.save_timer_state();
+
+        if let Err(e) = snooze::record(minutes, source) {
+            log::warn!("연장 기록 저장 실패: {}", e);
+        }
+        self.snooze_stats = snooze::stats().unwrap_or(self.snooze_stats);
+
+        let message = format!(
+            "타이머를 {}분 연장했습니다 ({})",
+            minutes,
+            self.timer.remaining_text()
+        );
+        log::info!("{}", message);
+        Ok(message)
+    }
+
+    /// 다른 프로세스에서 받은 명령을 처리하고 결과를 돌려줌
+    fn poll_ipc(&mut self) {
+        let mut requests = Vec::new();
+        if let Some(server) = &self.ipc_server {
+            while let Some(request) = server.try_recv() {
+                requests.push(request);
+            }
+        }
+
+        for IpcRequest { command, reply } in requests {
+            let result = match command {
+                IpcCommand::Snooze(minutes) => self.snooze(minutes, "cli"),
+            };
+            let _ = reply.send(result);
+        }
+    }
+
+    /// 동작 중에 타이머 설정을 바꾸면 경과 시간을 유지한 채 반영
+    fn apply_timer_settings(&mut self) {
+        match self.settings.timer_mode {
+            TimerMode::Duration => self.timer.set_duration(self.settings.timer_minutes),
+            TimerMode::Until => {
+                if matches!(
+                    self.timer.get_state(),
+                    TimerState::Running | TimerState::Paused
+                ) {
+                    let deadline = next_deadline(&self.clock.now(), self.settings.timer_until);
+                    self.timer.set_deadline(deadline);
+                }
+            }
+        }
+        self.save_timer_state();
+    }
+
+    /// 절전에서 깨어났는지 확인하고 설정에 따라 절전 시간을 타이머에 반영
+    fn poll_suspend(&mut self) {
+        let Some(gap) = self.suspend_detector.poll(self.clock.as_ref()) else {
+            return;
+        };
+
+        // 종료 시각 모드는 벽시계 기준이므로 항상 절전 시간을 포함
+        let counted =
+            self.settings.count_sleep_toward_timer || self.settings.timer_mode == TimerMode::Until;
+        log::info!(
+            "절전 감지: {} (타이머에 {})",
+            format_duration(gap),
+            if counted {
+                "포함"
+            } else {
+                "포함하지 않음"
+            }
+        );
+
+        if counted {
+            self.timer.add_elapsed(gap);
+            self.work_cycle.add_elapsed(gap);
+        }
+        self.save_timer_state();
+        self.last_suspend = Some((self.clock.now(), gap));
+    }
+
+    /// 설정 파일이 밖에서 바뀌었으면 다시 불러와 적용
+    fn poll_config_file(&mut self) {
+        if !self
+            .config_watcher
+            .as_ref()
+            .is_some_and(ConfigWatcher::take_change)
+        {
+            return;
+        }
+
+        let content = read_config_file();
+        if content.is_none() || content == self.config_snapshot {
+            return;
+        }
+        self.config_snapshot = content;
+
+        let result = if self.profile_name != DEFAULT_PROFILE {
+            Err(format!(
+                "설정 파일이 바뀌었지만 '{}' 프로필을 사용 중이라 적용하지 않았습니다",
+                self.profile_name
+            ))
+        } else {
+            match Settings::config_path().and_then(|path| Settings::load_from(&path)) {
+                Ok((settings, warnings)) => {
+                    self.apply_reloaded_settings(settings);
+                    let message = if warnings.is_empty() {
+                        "설정 파일을 다시 불러왔습니다".to_string()
+                    } else {
+                        format!("설정 파일을 다시 불러왔습니다 (경고 {}개)", warnings.len())
+                    };
+                    self.config_warnings = warnings;
+                    Ok(message)
+                }
+                Err(e) => Err(format!(
+                    "설정 파일을 다시 불러오지 못해 기존 설정을 유지합니다: {}",
+                    e
+                )),
+            }
+        };
+
+        match &result {
+            Ok(message) => log::info!("{}", message),
+            Err(message) => log::warn!("{}", message),
+        }
+        self.config_reload = Some((self.clock.instant(), result));
+    }
+
+    /// 다시 불러온 설정을 동작 중인 컨트롤러와 타이머에 반영 (저장하지 않은 변경은 버림)
+    fn apply_reloaded_settings(&mut self, settings: Settings) {
+        self.settings = self.with_overrides(&settings);
+        self.file_settings = settings;
+
+        if self.is_active {
+            if let Ok(mut controller) = self.mouse_controller.lock() {
+                controller.restart(self.settings.clone());
+            }
+        }
+        self.apply_timer_settings();
+        self.refresh_pause();
+    }
+
+    fn update_status(&mut self) {
+        self.poll_config_file();
+        self.poll_suspend();
+        self.poll_ipc();
+        self.poll_monitors();
+
+        // 타이머 만료 체크
+        if self.settings.enable_timer && self.timer.is_expired() && self.is_active {
+            self.run_expiry_actions();
+            self.save_timer_state();
+        }
+
+        if self.settings.enable_timer && self.is_active {
+            self.check_expiry_warning();
+        }
+
+        if self.is_active {
+            self.poll_cycle();
+        }
+
+        if self.is_active {
+            let mut status = match &self.pause_reason {
+                Some(reason) => format!("일시정지됨 - {}", reason),
+                None => format!(
+                    "활성화됨 - 마지막 동작: {}초 전",
+                    self.clock
+                        .instant()
+                        .saturating_duration_since(self.last_activity)
+                        .as_secs()
+                ),
+            };
+
+            // 타이머 정보 추가
+            if self.settings.enable_timer {
+                status.push_str(&format!(" ({})", self.timer.remaining_text()));
+            }
+            if self.work_cycle.is_running() {
+                status.push_str(&format!(" [{}]", self.cycle_text()));
+            }
+
+            self.status_text = status;
+        } else if let Some(reason) = &self.stop_reason {
+            self.status_text = reason.clone();
+        } else if let Some(watcher) = &self.process_watcher {
+            self.status_text = format!("프로세스 대기 중: {}", watcher.target().describe());
+        } else {
+            self.status_text = "비활성화됨".to_string();
+        }
+    }
+}
+
+impl eframe::App for AutoMouseApp {
+    fn update(&mut self, ctx: &Context, _frame: &mut eframe::Frame) {
+        // 첫 번째 업데이트에서 시작 시 최소화 처리
+        if self.should_minimize {
+            ctx.send_viewport_cmd(egui::viewport::ViewportCommand::Minimized(true));
+            self.should_minimize = false;
+        }
+
+        self.update_status();
+
+        // 만료 동작으로 종료가 요청된 경우
+        if self.quit_requested {
+            ctx.send_viewport_cmd(egui::viewport::ViewportCommand::Close);
+        }
+
+        egui::CentralPanel::default().show(ctx, |ui| {
+            ui.heading("Stay Awake...");
+            ui.separator();
+
+            // 상태 표시
+            ui.label(format!("상태: {}", self.status_text));
+            self.config_reload_ui(ui);
+            for error in &self.expiry_errors {
+                ui.colored_label(egui::Color32::RED, error);
+            }
+            if self.expiry_warned && self.is_active {
+                self.expiry_warning_ui(ui);
+            }
+            self.resume_ui(ui);
+            self.config_warnings_ui(ui);
+            ui.separator();
+
+            self.collapsible_ui(ui, ctx);
+
+            // 창 크기를 콘텐츠에 맞게 자동 조정
+            ui.allocate_space(ui.available_size());
+        });
+
+        // 축소/확대 상태가 변경될 때 창 크기 자동 조정
+        if self.should_resize() {
+            ctx.send_viewport_cmd(egui::viewport::ViewportCommand::Resizable(true));
+
+            // 상태에 따른 적절한 창 크기 설정
+            let target_size = if self.is_collapsed {
+                egui::vec2(300.0, 200.0) // 축소 시 크기
+            } else {
+                egui::vec2(300.0, 450.0) // 확대 시 크기
+            };
+
+            ctx.send_viewport_cmd(egui::viewport::ViewportCommand::InnerSize(target_size));
+            self.last_collapsed_state = self.is_collapsed;
+        }
+
+        // 자동 업데이트
+        ctx.request_repaint_after(Duration::from_secs(1));
+    }
+}
+
+impl AutoMouseApp {
+    fn collapsible_ui(&mut self, ui: &mut Ui, _ctx: &Context) {
+        // Controls는 항상 표시 (맨 위로 이동)
+        ui.heading("Controls");
+        self.control_ui(ui);
+
+        ui.separator();
+
+        // 확대/축소 버튼
+        let button_text = if self.is_collapsed {
+            "▼ 확대"
+        } else {
+            "▲ 축소"
+        };
+        if ui.button(button_text).clicked() {
+            self.is_collapsed = !self.is_collapsed;
+        }
+
+        if !self.is_collapsed {
+            // Expanded view: show other sections
+            ui.separator();
+
+            egui::ScrollArea::vertical().show(ui, |ui| {
+                ui.heading("Settings");
+                self.settings_ui(ui);
+
+                ui.separator();
+
+                ui.heading("Profiles");
+                self.profiles_ui(ui);
+
+                ui.separator();
+
+                ui.heading("Import/Export");
+                self.bundle_ui(ui);
+
+                ui.separator();
+
+                ui.heading("Schedule");
+                self.schedule_ui(ui);
+
+                ui.separator();
+
+                ui.heading("Cron");
+                self.cron_ui(ui);
+
+                ui.separator();
+
+                ui.heading("Work Cycle");
+                self.cycle_ui(ui);
+
+                ui.separator();
+
+                ui.heading("Statistics");
+                self.stats_ui(ui);
+            });
+        }
+
+        // 창 크기를 콘텐츠에 맞게 자동 조정
+        ui.ctx().request_repaint();
+    }
+
+    fn settings_ui(&mut self, ui: &mut Ui) {
+        // 설정 파일을 직접 고친 경우 등 범위를 벗어난 값을 표시
+        let errors = self.settings.validate().err().unwrap_or_default();
+
+        ui.horizontal(|ui| {
+            ui.label("동작 간격 (초):");
+            ui.add(egui::Slider::new(
+                &mut self.settings.interval_seconds,
+                1.0..=300.0,
+            ));
+            widgets::field_error(ui, &errors, "interval_seconds");
+        });
+
+        ui.horizontal(|ui| {
+            ui.label("이동 거리 (픽셀):");
+            ui.add(egui::Slider::new(&mut self.settings.move_distance, 1..=100));
+            widgets::field_error(ui, &errors, "move_distance");
+        });
+
+        ui.horizontal(|ui| {
+            ui.label("동작 방식:");
+            egui::ComboBox::from_id_source("activity_kind")
+                .selected_text(self.settings.activity_kind.label())
+                .show_ui(ui, |ui| {
+                    for kind in ActivityKind::ALL {
+                        ui.selectable_value(&mut self.settings.activity_kind, kind, kind.label());
+                    }
+                });
+        });
+
+        ui.horizontal(|ui| {
+            ui.checkbox(&mut self.settings.fullscreen.detect, "전체 화면 감지");
+            if !fullscreen::SUPPORTED {
+                ui.colored_label(egui::Color32::GRAY, "(이 플랫폼에서는 지원하지 않음)");
+            }
+        });
+
+        if self.settings.fullscreen.detect {
+            // 동작 종류별 전체 화면 처리 방식
+            for kind in ActivityKind::ALL {
+                ui.horizontal(|ui| {
+                    ui.label(format!("전체 화면 시 {}:", kind.label()));
+                    let action = self.settings.fullscreen.action_for_mut(kind);
+                    egui::ComboBox::from_id_source(("fullscreen_action", kind.label()))
+                        .selected_text(action.label())
+                        .show_ui(ui, |ui| {
+                            for candidate in FullscreenAction::ALL {
+                                ui.selectable_value(action, candidate, candidate.label());
+                            }
+                        });
+                });
+            }
+        }
+
+        ui.checkbox(&mut self.settings.enable_sound, "소리 알림");
+        ui.checkbox(
+            &mut self.settings.enable_notifications,
+            "중지 시 데스크톱 알림",
+        );
+        ui.checkbox(&mut self.settings.start_minimized, "시작 시 최소화");
+
+        ui.separator();
+
+        // 프로세스 감시 설정 UI
+        ui.checkbox(
+            &mut self.settings.process_watch.enabled,
+            "프로세스 실행 중에만 동작",
+        );
+
+        if self.settings.process_watch.enabled {
+            ui.horizontal(|ui| {
+                ui.label("PID 또는 이름 패턴:");
+                ui.text_edit_singleline(&mut self.settings.process_watch.target);
+            });
+
+            match &self.process_watcher {
+                Some(watcher) => match watcher.current_pid() {
+                    Some(pid) => ui.label(format!("감시 중: PID {}", pid)),
+                    None => ui.label("프로세스 대기 중"),
+                },
+                None => ui.colored_label(egui::Color32::RED, "감시 대상을 입력하세요"),
+            };
+        }
+
+        ui.separator();
+
+        // 시스템 부하 트리거 설정 UI
+        ui.checkbox(
+            &mut self.settings.resource_trigger.enabled,
+            "시스템 부하가 높을 때 자동 시작",
+        );
+
+        if self.settings.resource_trigger.enabled {
+            let trigger = &mut self.settings.resource_trigger;
+            ui.horizontal(|ui| {
+                ui.label("CPU (%):");
+                ui.add(egui::Slider::new(&mut trigger.cpu_percent, 0.0..=100.0));
+                widgets::field_error(ui, &errors, "resource_trigger.cpu_percent");
+            });
+            ui.horizontal(|ui| {
+                ui.label("디스크 (MB/s):");
+                ui.add(egui::Slider::new(&mut trigger.disk_mb_per_sec, 0.0..=500.0));
+                widgets::field_error(ui, &errors, "resource_trigger.disk_mb_per_sec");
+            });
+            ui.horizontal(|ui| {
+                ui.label("네트워크 (MB/s):");
+                ui.add(egui::Slider::new(&mut trigger.net_mb_per_sec, 0.0..=100.0));
+                widgets::field_error(ui, &errors, "resource_trigger.net_mb_per_sec");
+            });
+            ui.horizontal(|ui| {
+                ui.label("평균 구간 (초):");
+                ui.add(egui::Slider::new(&mut trigger.window_seconds, 1..=300));
+                widgets::field_error(ui, &errors, "resource_trigger.window_seconds");
+            });
+            ui.horizontal(|ui| {
+                ui.label("중지 대기 (초):");
+                ui.add(egui::Slider::new(&mut trigger.cooldown_seconds, 0..=1800));
+                widgets::field_error(ui, &errors, "resource_trigger.cooldown_seconds");
+            });
+            ui.label("0으로 설정한 항목은 확인하지 않습니다");
+        }
+
+        ui.horizontal(|ui| {
+            ui.checkbox(
+                &mut self.settings.pause_when_locked,
+                "화면 잠금 시 일시정지",
+            );
+            if self.settings.pause_when_locked && !self.lock_watcher.is_available() {
+                ui.colored_label(egui::Color32::GRAY, "(잠금 상태 확인 불가)");
+            }
+        });
+
+        ui.separator();
+
+        // 전원 설정 UI
+        ui.checkbox(
+            &mut self.settings.power.pause_on_battery,
+            "배터리 사용 중 일시정지",
+        );
+        ui.horizontal(|ui| {
+            ui.label("배터리 잔량 기준 (%):");
+            ui.add(egui::Slider::new(
+                &mut self.settings.power.pause_below_percent,
+                0..=100,
+            ));
+            widgets::field_error(ui, &errors, "power.pause_below_percent");
+        });
+        ui.horizontal(|ui| {
+            ui.label("배터리 사용 시 간격 (초):");
+            ui.add(egui::Slider::new(
+                &mut self.settings.power.battery_interval_seconds,
+                0.0..=600.0,
+            ));
+            widgets::field_error(ui, &errors, "power.battery_interval_seconds");
+        });
+        ui.label("0으로 설정한 항목은 사용하지 않습니다");
+
+        ui.separator();
+
+        // 타이머 설정 UI
+        ui.checkbox(&mut self.settings.enable_timer, "자동 타이머 사용");
+
+        if self.settings.enable_timer {
+            let mut changed = false;
+
+            ui.horizontal(|ui| {
+                changed |= ui
+                    .radio_value(
+                        &mut self.settings.timer_mode,
+                        TimerMode::Duration,
+                        "시간 (분)",
+                    )
+                    .changed();
+                changed |= ui
+                    .radio_value(&mut self.settings.timer_mode, TimerMode::Until, "종료 시각")
+                    .changed();
+            });
+
+            ui.horizontal(|ui| match self.settings.timer_mode {
+                TimerMode::Duration => {
+                    ui.label("타이머 시간 (분):");
+                    changed |= ui
+                        .add(
+                            egui::Slider::new(&mut self.settings.timer_minutes, 1..=480).text("분"),
+                        )
+                        .changed();
+                    widgets::field_error(ui, &errors, "timer_minutes");
+                }
+                TimerMode::Until => {
+                    ui.label("종료 시각:");
+                    changed |= widgets::time_edit(ui, &mut self.settings.timer_until);
+                    ui.label("까지");
+                }
+            });
+
+            if changed {
+                self.apply_timer_settings();
+            }
+
+            ui.horizontal(|ui| {
+                ui.label("만료 경고:");
+                ui.add(
+                    egui::DragValue::new(&mut self.settings.warn_before_minutes)
+                        .clamp_range(0..=120)
+                        .suffix("분 전"),
+                );
+                widgets::field_error(ui, &errors, "warn_before_minutes");
+            });
+
+            ui.add_enabled(
+                self.settings.timer_mode == TimerMode::Duration,
+                egui::Checkbox::new(
+                    &mut self.settings.count_sleep_toward_timer,
+                    "절전(대기 모드) 시간도 타이머에 포함",
+                ),
+            )
+            .on_disabled_hover_text("종료 시각 모드에서는 항상 포함됩니다");
+
+            ui.checkbox(
+                &mut self.settings.auto_resume_timer,
+                "재시작 시 남은 타이머를 묻지 않고 이어서 진행",
+            );
+
+            ui.label("만료 시 동작 (위에서부터 순서대로):");
+            Self::expiry_actions_ui(ui, &mut self.settings.expiry_actions);
+
+            // 타이머 상태 표시
+            match self.timer.get_state() {
+                TimerState::Stopped => {
+                    ui.label("타이머: 비활성화");
+                }
+                TimerState::Running => {
+                    ui.label(format!("남은 시간: {}", self.timer.remaining_text()));
+                }
+                TimerState::Paused => {
+                    ui.label(format!("일시정지됨: {}", self.timer.remaining_text()));
+                }
+                TimerState::Expired => {
+                    ui.colored_label(egui::Color32::RED, "타이머: 시간 만료");
+                }
+            }
+        }
+
+        if !errors.is_empty() {
+            ui.colored_label(
+                egui::Color32::RED,
+                format!(
+                    "허용 범위를 벗어난 값이 {}개 있어 저장할 수 없습니다",
+                    errors.len()
+                ),
+            );
+        }
+
+        if let Ok(location) = ConfigLocation::current() {
+            ui.small(format!("설정 위치: {}", location.describe()));
+        }
+
+        if ui
+            .button(format!("설정 저장 ({})", self.profile_name))
+            .clicked()
+        {
+            let settings = self.settings_to_save();
+            if let Err(e) = self.profiles.save_profile(&self.profile_name, &settings) {
+                self.status_text = format!("설정 저장 실패: {}", e);
+            } else {
+                self.status_text = "설정이 저장되었습니다".to_string();
+                self.file_settings = settings;
+                // 직접 저장한 내용은 다시 불러오지 않음
+                self.config_snapshot = read_config_file();
+            }
+        }
+    }
+
+    fn schedule_ui(&mut self, ui: &mut Ui) {
+        let profile_names = self.profiles.names();
+        let schedule = &mut self.settings.schedule;
+        ui.checkbox(&mut schedule.enabled, "시간표에 따라 자동 시작/중지");
+
+        egui::Grid::new("schedule_grid")
+            .striped(true)
+            .show(ui, |ui| {
+                for (day, ranges) in schedule.days.iter_mut().enumerate() {
+                    ui.label(WEEKDAY_LABELS[day]);
+                    ui.vertical(|ui| Self::day_ranges_ui(ui, day, ranges, &profile_names));
+                    ui.end_row();
+                }
+            });
+
+        if ui.button("평일 09:00 ~ 18:00으로 초기화").clicked() {
+            schedule.days = Schedule::working_hours();
+        }
+
+        if schedule.enabled {
+            match schedule.current_range() {
+                Some(range) => ui.label(format!(
+                    "현재 구간: {} ~ {}",
+                    range.start.format("%H:%M"),
+                    range.end.format("%H:%M")
+                )),
+                None => ui.label("현재 시간표 구간 아님"),
+            };
+        }
+    }
+
+    fn day_ranges_ui(
+        ui: &mut Ui,
+        day: usize,
+        ranges: &mut Vec<TimeRange>,
+        profile_names: &[String],
+    ) {
+        let mut removed = None;
+
+        for (index, range) in ranges.iter_mut().enumerate() {
+            ui.horizontal(|ui| {
+                widgets::time_edit(ui, &mut range.start);
+                ui.label("~");
+                widgets::time_edit(ui, &mut range.end);
+                if ui.small_button("✕").clicked() {
+                    removed = Some(index);
+                }
+            });
+
+            ui.horizontal(|ui| {
+                ui.label("프로필:");
+                egui::ComboBox::from_id_source(("schedule_profile", day, index))
+                    .selected_text(range.profile.as_deref().unwrap_or("현재 설정"))
+                    .show_ui(ui, |ui| {
+                        ui.selectable_value(&mut range.profile, None, "현재 설정");
+                        for name in profile_names {
+                            ui.selectable_value(
+                                &mut range.profile,
+                                Some(name.clone()),
+                                name.as_str(),
+                            );
+                        }
+                    });
+            });
+        }
+
+        if let Some(index) = removed {
+            ranges.remove(index);
+        }
+
+        if ui.small_button("+ 구간 추가").clicked() {
+            let start = NaiveTime::from_hms_opt(9, 0, 0).unwrap();
+            let end = NaiveTime::from_hms_opt(18, 0, 0).unwrap();
+            ranges.push(TimeRange::new(start, end));
+        }
+    }
+
+    fn cron_ui(&mut self, ui: &mut Ui) {
+        self.cron_windows.update(&self.settings.cron.triggers);
+        let cron = &mut self.settings.cron;
+        ui.checkbox(&mut cron.enabled, "cron 표현식으로 자동 시작/중지");
+        ui.label("형식: 분 시 일 월 요일 (예: 0 8 * * MON#1)");
+
+        let mut removed = None;
+        for (index, trigger) in cron.triggers.iter_mut().enumerate() {
+            egui::Grid::new(("cron_trigger", index)).show(ui, |ui| {
+                ui.label("시작:");
+                ui.text_edit_singleline(&mut trigger.start);
+                ui.end_row();
+
+                ui.label("중지:");
+                ui.text_edit_singleline(&mut trigger.stop);
+                if ui.small_button("✕").clicked() {
+                    removed = Some(index);
+                }
+                ui.end_row();
+            });
+
+            if let Some(e) = self.cron_windows.error(index) {
+                ui.colored_label(egui::Color32::RED, e.to_string());
+            }
+        }
+
+        if let Some(index) = removed {
+            cron.triggers.remove(index);
+        }
+
+        if ui.small_button("+ 트리거 추가").clicked() {
+            cron.triggers.push(CronTrigger::default());
+        }
+
+        // 다음 실행 시각 미리보기
+        self.cron_windows.update(&self.settings.cron.triggers);
+        let upcoming = self.cron_evaluator.upcoming(self.cron_windows.windows(), 5);
+        if !upcoming.is_empty() {
+            ui.label("다음 실행:");
+            for (time, event) in upcoming {
+                ui.label(format!(
+                    "  {} {}",
+                    time.format("%Y-%m-%d (%a) %H:%M"),
+                    event.label()
+                ));
+            }
+        }
+    }
+
+    fn cycle_ui(&mut self, ui: &mut Ui) {
+        let errors = self.settings.validate().err().unwrap_or_default();
+        let cycle = &mut self.settings.cycle;
+        ui.checkbox(
+            &mut cycle.enabled,
+            "작업/휴식 주기 사용 (휴식 중에는 동작 안 함)",
+        );
+
+        egui::Grid::new("cycle_grid").show(ui, |ui| {
+            for (label, field, value) in [
+                ("작업 시간:", "cycle.work_minutes", &mut cycle.work_minutes),
+                (
+                    "짧은 휴식:",
+                    "cycle.short_break_minutes",
+                    &mut cycle.short_break_minutes,
+                ),
+                (
+                    "긴 휴식:",
+                    "cycle.long_break_minutes",
+                    &mut cycle.long_break_minutes,
+                ),
+            ] {
+                ui.label(label);
+                ui.add(
+                    egui::DragValue::new(value)
+                        .clamp_range(1..=240)
+                        .suffix("분"),
+                );
+                widgets::field_error(ui, &errors, field);
+                ui.end_row();
+            }
+
+            ui.label("긴 휴식 주기:");
+            ui.add(
+                egui::DragValue::new(&mut cycle.long_break_every)
+                    .clamp_range(0..=20)
+                    .suffix("회마다"),
+            );
+            widgets::field_error(ui, &errors, "cycle.long_break_every");
+            ui.end_row();
+        });
+        ui.label("긴 휴식 주기를 0으로 설정하면 짧은 휴식만 사용합니다. 변경한 시간은 다음 구간부터 반영됩니다");
+
+        if self.work_cycle.is_running() {
+            ui.label(format!(
+                "현재: {} (완료한 작업 {}회)",
+                self.cycle_text(),
+                self.work_cycle.completed()
+            ));
+        } else if self.is_active && self.settings.cycle.enabled {
+            ui.label("다음 시작부터 주기가 적용됩니다");
+        }
+    }
+
+    fn expiry_actions_ui(ui: &mut Ui, actions: &mut Vec<ExpiryAction>) {
+        let mut removed = None;
+        let mut moved_up = None;
+
+        for (index, action) in actions.iter_mut().enumerate() {
+            ui.horizontal(|ui| {
+                egui::ComboBox::from_id_source(("expiry_action", index))
+                    .selected_text(action.label())
+                    .show_ui(ui, |ui| {
+                        for template in ExpiryAction::templates() {
+                            let selected = action.same_kind(&template);
+                            if ui.selectable_label(selected, template.label()).clicked()
+                                && !selected
+                            {
+                                *action = template;
+                            }
+                        }
+                    });
+
+                match action {
+                    ExpiryAction::RunCommand { command } => {
+                        ui.add(
+                            egui::TextEdit::singleline(command)
+                                .hint_text("셸 명령")
+                                .desired_width(120.0),
+                        );
+                    }
+                    ExpiryAction::Notify { message } => {
+                        ui.add(egui::TextEdit::singleline(message).desired_width(120.0));
+                    }
+                    _ => {}
+                }
+
+                if index > 0 && ui.small_button("▲").clicked() {
+                    moved_up = Some(index);
+                }
+                if ui.small_button("✕").clicked() {
+                    removed = Some(index);
+                }
+            });
+        }
+
+        if let Some(index) = moved_up {
+            actions.swap(index - 1, index);
+        }
+        if let Some(index) = removed {
+            actions.remove(index);
+        }
+
+        if ui.small_button("+ 동작 추가").clicked() {
+            actions.push(ExpiryAction::Stop);
+        }
+
+        if expiry::keeps_running(actions) {
+            ui.colored_label(
+                egui::Color32::YELLOW,
+                "중지나 프로그램 종료가 없어 타이머가 만료된 뒤에도 계속 동작합니다",
+            );
+        }
+    }
+
+    fn expiry_warning_ui(&mut self, ui: &mut Ui) {
+        ui.colored_label(
+            egui::Color32::YELLOW,
+            format!("타이머가 곧 만료됩니다 ({})", self.timer.remaining_text()),
+        );
+        ui.horizontal(|ui| {
+            for (minutes, label) in SNOOZE_CHOICES {
+                if ui.button(label).clicked() {
+                    if let Err(e) = self.snooze(minutes, "gui") {
+                        self.status_text = e;
+                    }
+                }
+            }
+        });
+    }
+
+    /// 설정 파일을 다시 불러온 결과 (성공은 잠시 표시, 실패는 다음 변경까지 표시)
+    fn config_reload_ui(&mut self, ui: &mut Ui) {
+        match &self.config_reload {
+            Some((at, Ok(message)))
+                if self.clock.instant().saturating_duration_since(*at) < RELOAD_NOTICE =>
+            {
+                ui.label(message);
+            }
+            Some((_, Err(message))) => {
+                ui.colored_label(egui::Color32::RED, message);
+            }
+            _ => {}
+        }
+    }
+
+    fn config_warnings_ui(&mut self, ui: &mut Ui) {
+        if self.config_warnings.is_empty() {
+            return;
+        }
+
+        for warning in &self.config_warnings {
+            ui.colored_label(egui::Color32::YELLOW, warning);
+        }
+        if ui.small_button("확인").clicked() {
+            self.config_warnings.clear();
+        }
+    }
+
+    /// 이전 타이머를 이어서 진행할지 묻거나 재개 결과를 안내
+    fn resume_ui(&mut self, ui: &mut Ui) {
+        if let Some(saved) = self.pending_resume.clone() {
+            ui.colored_label(
+                egui::Color32::YELLOW,
+                format!("이전 실행의 타이머가 남아 있습니다: {}", saved.describe()),
+            );
+            ui.horizontal(|ui| {
+                if ui.button("이어서 시작").clicked() {
+                    self.resume_saved_timer(saved);
+                }
+                if ui.button("무시").clicked() {
+                    self.pending_resume = None;
+                    self.clear_saved_timer();
+                }
+            });
+        } else if let Some(notice) = self.resume_notice.clone() {
+            ui.horizontal(|ui| {
+                ui.label(notice);
+                if ui.small_button("닫기").clicked() {
+                    self.resume_notice = None;
+                }
+            });
+        }
+    }
+
+    fn control_ui(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            if ui
+                .button(if self.is_active { "중지" } else { "시작" })
+                .clicked()
+            {
+                if self.is_active {
+                    self.stop();
+                } else {
+                    self.start(StartSource::Manual);
+                }
+            }
+
+            if self.is_active {
+                let label = if self.user_paused {
+                    "재개"
+                } else {
+                    "일시정지"
+                };
+                if ui.button(label).clicked() {
+                    self.user_paused = !self.user_paused;
+                    self.refresh_pause();
+                }
+            }
+
+            if ui.button("테스트 동작").clicked() {
+                if let Ok(mut controller) = self.mouse_controller.lock() {
+                    controller.move_mouse(self.settings.move_distance);
+                }
+            }
+
+            // 동작 중에 설정을 통째로 바꾸지 않도록 중지한 뒤에만 전환
+            let mut selected = self.profile_name.clone();
+            ui.add_enabled_ui(!self.is_active, |ui| {
+                egui::ComboBox::from_id_source("profile_select")
+                    .selected_text(selected.as_str())
+                    .show_ui(ui, |ui| {
+                        for name in self.profiles.names() {
+                            ui.selectable_value(&mut selected, name.clone(), name);
+                        }
+                    });
+            })
+            .response
+            .on_disabled_hover_text("중지한 뒤 바꿀 수 있습니다");
+            if selected != self.profile_name {
+                self.switch_profile(&selected);
+            }
+        });
+    }
+
+    fn profiles_ui(&mut self, ui: &mut Ui) {
+        ui.label(format!("현재 프로필: {}", self.profile_name));
+
+        ui.horizontal(|ui| {
+            ui.label("이름:");
+            ui.add(
+                egui::TextEdit::singleline(&mut self.profile_name_input)
+                    .hint_text("새 프로필 이름")
+                    .desired_width(120.0),
+            );
+        });
+
+        // 만들기/복제/이름 바꾸기/삭제는 새 프로필로 전환하므로 중지 상태에서만 가능
+        ui.add_enabled_ui(!self.is_active, |ui| {
+            ui.horizontal(|ui| {
+                let name = self.profile_name_input.clone();
+                let current = self.profile_name.clone();
+
+                if ui
+                    .button("새로 만들기")
+                    .on_hover_text("현재 설정으로 새 프로필을 만듦")
+                    .clicked()
+                {
+                    let result = self.profiles.create(&name, self.settings_to_save());
+                    self.apply_profile_change(result.map(Some));
+                }
+                if ui
+                    .button("복제")
+                    .on_hover_text("현재 프로필의 저장된 설정을 복사")
+                    .clicked()
+                {
+                    let result = self.profiles.duplicate(&current, &name);
+                    self.apply_profile_change(result.map(Some));
+                }
+
+                ui.add_enabled_ui(current != DEFAULT_PROFILE, |ui| {
+                    if ui.button("이름 바꾸기").clicked() {
+                        let result = self
+                            .default_profile_settings()
+                            .and_then(|default| self.profiles.rename(&current, &name, &default));
+                        self.apply_profile_change(result.map(Some));
+                    }
+                    if ui.button("삭제").clicked() {
+                        let result = self
+                            .default_profile_settings()
+                            .and_then(|default| self.profiles.delete(&current, &default))
+                            .map(|_| Some(DEFAULT_PROFILE.to_string()));
+                        self.apply_profile_change(result);
+                    }
+                });
+            })
+        });
+
+        if let Some(error) = &self.profile_error {
+            ui.colored_label(egui::Color32::RED, error);
+        }
+    }
+
+    fn bundle_ui(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            ui.label("파일:");
+            if ui
+                .add(egui::TextEdit::singleline(&mut self.bundle_path).desired_width(220.0))
+                .changed()
+            {
+                self.bundle_preview = None;
+            }
+        });
+
+        ui.horizontal(|ui| {
+            ui.checkbox(&mut self.bundle_options.profiles, "모든 프로필");
+            ui.checkbox(&mut self.bundle_options.schedule, "시간표/Cron");
+            ui.checkbox(&mut self.bundle_options.rules, "규칙")
+                .on_hover_text("프로세스 감시, 부하 트리거, 전원, 만료 시 동작");
+        });
+
+        ui.horizontal(|ui| {
+            if ui
+                .button(format!("내보내기 ({})", self.profile_name))
+                .on_hover_text("저장하지 않은 변경도 포함, 환경 변수와 명령줄 값은 제외")
+                .clicked()
+            {
+                let bundle = Bundle::new(
+                    &self.settings_to_save(),
+                    &self.profile_name,
+                    &self.profiles,
+                    self.bundle_options,
+                );
+                self.bundle_result = Some(
+                    bundle
+                        .save(Path::new(&self.bundle_path))
+                        .map(|_| format!("내보냈습니다: {}", bundle.describe()))
+                        .map_err(|e| format!("내보내기 실패: {}", e)),
+                );
+            }
+            if ui
+                .button("가져오기 미리보기")
+                .on_hover_text("파일을 가져오면 바뀌는 내용을 확인")
+                .clicked()
+            {
+                match Bundle::load(Path::new(&self.bundle_path)) {
+                    Ok(bundle) => {
+                        let preview = bundle.preview(&self.file_settings, &self.profiles);
+                        self.bundle_preview = Some((bundle, preview));
+                        self.bundle_result = None;
+                    }
+                    Err(e) => {
+                        self.bundle_preview = None;
+                        self.bundle_result = Some(Err(format!("가져오기 실패: {}", e)));
+                    }
+                }
+            }
+        });
+
+        if let Some((bundle, preview)) = &self.bundle_preview {
+            ui.small(bundle.describe());
+            for warning in &preview.warnings {
+                ui.colored_label(egui::Color32::YELLOW, warning);
+            }
+
+            if preview.is_empty() {
+                ui.label("바뀌는 내용이 없습니다");
+            } else {
+                egui::ScrollArea::vertical()
+                    .id_source("bundle_preview")
+                    .max_height(150.0)
+                    .show(ui, |ui| {
+                        for change in &preview.changes {
+                            ui.small(change.to_string());
+                        }
+                        for (name, change) in &preview.profile_changes {
+                            ui.small(format!("프로필 {}: {}", name, change));
+                        }
+                    });
+            }
+            if !preview.commands.is_empty() {
+                ui.colored_label(
+                    egui::Color32::YELLOW,
+                    "주의: 타이머가 만료되면 다음 명령을 셸(sh -c)로 실행합니다",
+                );
+                for (name, command) in &preview.commands {
+                    ui.small(format!(
+                        "[{}] {}",
+                        name.as_deref().unwrap_or(&self.profile_name),
+                        command
+                    ));
+                }
+            }
+
+            let mut close = false;
+            ui.horizontal(|ui| {
+                // 가져온 설정으로 바로 전환하므로 중지 상태에서만 가능
+                let enabled = !self.is_active && !preview.is_empty();
+                if ui
+                    .add_enabled(
+                        enabled,
+                        egui::Button::new(format!("가져오기 ({})", self.profile_name)),
+                    )
+                    .clicked()
+                {
+                    self.bundle_result = Some(
+                        preview
+                            .apply(&mut self.profiles, &self.profile_name)
+                            .map(|_| "가져왔습니다".to_string())
+                            .map_err(|e| format!("가져오기 실패: {}", e)),
+                    );
+                    close = self.bundle_result.as_ref().is_some_and(Result::is_ok);
+                }
+                if ui.button("취소").clicked() {
+                    close = true;
+                }
+            });
+
+            if close {
+                self.bundle_preview = None;
+                if self.bundle_result.as_ref().is_some_and(Result::is_ok) {
+                    let name = self.profile_name.clone();
+                    self.switch_profile(&name);
+                    // 직접 저장한 내용은 다시 불러오지 않음
+                    self.config_snapshot = read_config_file();
+                }
+            }
+        }
+
+        match &self.bundle_result {
+            Some(Ok(message)) => {
+                ui.small(message);
+            }
+            Some(Err(error)) => {
+                ui.colored_label(egui::Color32::RED, error);
+            }
+            None => {}
+        }
+    }
+
+    fn stats_ui(&mut self, ui: &mut Ui) {
+        if let Ok(controller) = self.mouse_controller.lock() {
+            ui.label(format!("총 동작 횟수: {}", controller.get_total_moves()));
+            ui.label(format!(
+                "마지막 동작 시간: {}",
+                controller.get_last_move_time().format("%H:%M:%S")
+            ));
+        }
+
+        if let Some((at, duration)) = self.last_suspend {
+            ui.label(format!(
+                "마지막 절전: {}에 깨어남 ({})",
+                at.format("%H:%M"),
+                format_duration(duration)
+            ));
+        }
+
+        if self.snooze_stats.total > 0 {
+            ui.label(format!(
+                "타이머 연장: 오늘 {}회, 전체 {}회 ({}분)",
+                self.snooze_stats.today, self.snooze_stats.total, self.snooze_stats.total_minutes
+            ));
+        }
+
+        match &self.power_state {
+            Some(state) => ui.label(format!("전원: {}", state.describe())),
+            None => ui.label("전원: 알 수 없음"),
+        };
+
+        if self.settings.resource_trigger.enabled {
+            ui.label(if self.resource_trigger.is_active() {
+                "부하 트리거: 높은 부하 감지됨"
+            } else {
+                "부하 트리거: 대기 중"
+            });
+        }
+
+        match self.resource_monitor.readings() {
+            Some(readings) => Self::resource_readings_ui(ui, &readings),
+            None => {
+                ui.label("시스템 부하: 측정 중");
+            }
+        }
+    }
+
+    fn resource_readings_ui(ui: &mut Ui, readings: &ResourceReadings) {
+        ui.label(format!("CPU 사용률: {:.1}%", readings.cpu_percent));
+        ui.label(format!(
+            "디스크: {:.2} MB/s",
+            readings.disk_bytes_per_sec / BYTES_PER_MB
+        ));
+        ui.label(format!(
+            "네트워크: {:.2} MB/s",
+            readings.net_bytes_per_sec / BYTES_PER_MB
+        ));
+    }
+
+    fn should_resize(&self) -> bool {
+        self.is_collapsed != self.last_collapsed_state
+    }
+}
+
+/// 내보내기/가져오기 파일의 기본 경로 (홈 디렉토리)
+fn default_bundle_path() -> String {
+    dirs::home_dir()
+        .unwrap_or_default()
+        .join("auto-mouse-bundle.json")
+        .display()
+        .to_string()
+}
+
+/// 현재 사용 중인 설정 파일의 내용 (없거나 읽을 수 없으면 None)
+fn read_config_file() -> Option<String> {
+    let path = Settings::config_path().ok()?;
+    std::fs::read_to_string(path).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn process_exit_stops_only_what_process_watch_started() {
+        let watch = StartSource::ProcessWatch;
+        assert!(watch.may_stop(Some(StartSource::ProcessWatch)));
+        assert!(!watch.may_stop(None));
+        for other in [
+            StartSource::Manual,
+            StartSource::ResourceLoad,
+            StartSource::Schedule,
+            StartSource::Cron,
+        ] {
+            assert!(!watch.may_stop(Some(other)), "{:?}", other);
+        }
     }
 }
@@ -1,3 +1,5 @@
+use crate::config::FieldError;
+
 // 위젯 관련 코드는 향후 확장 예정
 pub struct StatusWidget {
     pub text: String,
@@ -10,3 +12,50 @@ impl StatusWidget {
         }
     }
 }
+
+/// 시:분을 각각 드래그하여 편집하는 시간 입력 위젯 (변경 시 true)
+pub fn time_edit(ui: &mut egui::Ui, time: &mut chrono::NaiveTime) -> bool {
+    use chrono::Timelike;
+
+    let mut hour = time.hour();
+    let mut minute = time.minute();
+    let two_digits = |value: f64, _| format!("{:02}", value as u32);
+
+    let hour_changed = ui
+        .add(
+            egui::DragValue::new(&mut hour)
+                .clamp_range(0..=23)
+                .custom_formatter(two_digits),
+        )
+        .changed();
+    ui.label(":");
+    let minute_changed = ui
+        .add(
+            egui::DragValue::new(&mut minute)
+                .clamp_range(0..=59)
+                .custom_formatter(two_digits),
+        )
+        .changed();
+
+    if !(hour_changed || minute_changed) {
+        return false;
+    }
+    match chrono::NaiveTime::from_hms_opt(hour, minute, 0) {
+        Some(new_time) => {
+            *time = new_time;
+            true
+        }
+        None => false,
+    }
+}
+
+/// 해당 항목이 허용 범위를 벗어났으면 입력 옆에 범위를 빨간색으로 표시
+pub fn field_error(ui: &mut egui::Ui, errors: &[FieldError], field: &str) {
+    if let Some(error) = errors.iter().find(|e| e.field == field) {
+        ui.colored_label(
+            egui::Color32::RED,
+            format!("허용 범위: {}", error.allowed()),
+        )
+        .on_hover_text(error.to_string());
+    }
+}
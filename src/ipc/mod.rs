@@ -0,0 +1,3 @@
+pub mod socket;
+
+pub use socket::{send_command, IpcCommand, IpcRequest, IpcServer};
@@ -0,0 +1,245 @@
+use crate::config::ConfigLocation;
+use anyhow::Result;
+use std::path::PathBuf;
+use std::sync::mpsc::{self, Receiver, Sender};
+
+/// 실행 중인 프로그램에 보낼 수 있는 명령
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IpcCommand {
+    /// 타이머를 지정한 분만큼 연장
+    Snooze(u32),
+}
+
+impl IpcCommand {
+    pub fn parse(line: &str) -> Result<Self> {
+        let mut parts = line.split_whitespace();
+        match (parts.next(), parts.next()) {
+            (Some("snooze"), Some(minutes)) => {
+                let minutes = minutes
+                    .parse()
+                    .ok()
+                    .filter(|&minutes: &u32| minutes > 0)
+                    .ok_or_else(|| {
+                        anyhow::anyhow!("연장할 시간(분)이 올바르지 않습니다: {}", minutes)
+                    })?;
+                Ok(IpcCommand::Snooze(minutes))
+            }
+            _ => Err(anyhow::anyhow!("알 수 없는 명령: {}", line.trim())),
+        }
+    }
+
+    pub fn to_line(&self) -> String {
+        match self {
+            IpcCommand::Snooze(minutes) => format!("snooze {}", minutes),
+        }
+    }
+}
+
+/// 앱이 처리할 요청 (처리 결과는 `reply`로 돌려보냄)
+pub struct IpcRequest {
+    pub command: IpcCommand,
+    pub reply: Sender<Result<String, String>>,
+}
+
+/// 다른 프로세스의 명령을 받는 로컬 소켓 서버
+pub struct IpcServer {
+    requests: Receiver<IpcRequest>,
+}
+
+impl IpcServer {
+    /// 소켓을 열고 요청이 들어오면 `wake`를 호출하여 앱을 깨움
+    pub fn start<F>(wake: F) -> Result<Self>
+    where
+        F: Fn() + Send + Sync + 'static,
+    {
+        let (sender, requests) = mpsc::channel();
+
+        #[cfg(unix)]
+        {
+            unix::listen(socket_path(), sender, wake)?;
+            Ok(Self { requests })
+        }
+
+        #[cfg(not(unix))]
+        {
+            let _ = (sender, wake, requests);
+            Err(anyhow::anyhow!("지원되지 않는 플랫폼입니다"))
+        }
+    }
+
+    /// 대기 중인 요청을 하나 꺼냄 (없으면 None)
+    pub fn try_recv(&self) -> Option<IpcRequest> {
+        self.requests.try_recv().ok()
+    }
+}
+
+/// 실행 중인 프로그램에 명령을 보내고 응답을 반환
+pub fn send_command(command: &IpcCommand) -> Result<String> {
+    #[cfg(unix)]
+    {
+        unix::send(&socket_path(), command)
+    }
+
+    #[cfg(not(unix))]
+    {
+        let _ = command;
+        Err(anyhow::anyhow!("지원되지 않는 플랫폼입니다"))
+    }
+}
+
+/// 설정 파일마다 다른 소켓을 사용하여 여러 인스턴스를 함께 실행할 수 있도록 함
+fn socket_path() -> PathBuf {
+    let name = match ConfigLocation::current().ok().and_then(|l| l.instance_id()) {
+        Some(id) => format!("auto-mouse-{}", id),
+        None => "auto-mouse".to_string(),
+    };
+    match dirs::runtime_dir() {
+        Some(dir) => dir.join(format!("{}.sock", name)),
+        None => std::env::temp_dir().join(format!("{}-{}.sock", name, current_user_id())),
+    }
+}
+
+fn current_user_id() -> u32 {
+    #[cfg(unix)]
+    {
+        unsafe { libc::getuid() }
+    }
+
+    #[cfg(not(unix))]
+    {
+        0
+    }
+}
+
+#[cfg(unix)]
+mod unix {
+    use super::{IpcCommand, IpcRequest};
+    use anyhow::Result;
+    use std::io::{BufRead, BufReader, Write};
+    use std::os::unix::net::{UnixListener, UnixStream};
+    use std::path::Path;
+    use std::sync::mpsc::{self, Sender};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    const REPLY_TIMEOUT: Duration = Duration::from_secs(5);
+    // 명령을 보내지 않고 연결만 유지하는 클라이언트를 끊기까지의 시간
+    const CLIENT_TIMEOUT: Duration = Duration::from_secs(5);
+
+    pub fn listen<F>(path: std::path::PathBuf, sender: Sender<IpcRequest>, wake: F) -> Result<()>
+    where
+        F: Fn() + Send + Sync + 'static,
+    {
+        // 이미 실행 중인 인스턴스가 있으면 소켓을 빼앗지 않음
+        if UnixStream::connect(&path).is_ok() {
+            return Err(anyhow::anyhow!(
+                "다른 인스턴스가 이미 명령을 받고 있습니다: {}",
+                path.display()
+            ));
+        }
+        let _ = std::fs::remove_file(&path);
+        let listener = UnixListener::bind(&path)?;
+
+        let wake = Arc::new(wake);
+        std::thread::spawn(move || {
+            // 응답이 느린 클라이언트가 다른 요청을 막지 않도록 클라이언트마다 스레드 사용
+            for stream in listener.incoming().flatten() {
+                let sender = sender.clone();
+                let wake = Arc::clone(&wake);
+                std::thread::spawn(move || {
+                    if let Err(e) = handle_client(stream, &sender, wake.as_ref()) {
+                        log::warn!("명령 처리 실패: {}", e);
+                    }
+                });
+            }
+        });
+        Ok(())
+    }
+
+    fn handle_client<F: Fn()>(
+        mut stream: UnixStream,
+        sender: &Sender<IpcRequest>,
+        wake: &F,
+    ) -> Result<()> {
+        stream.set_read_timeout(Some(CLIENT_TIMEOUT))?;
+        stream.set_write_timeout(Some(CLIENT_TIMEOUT))?;
+
+        let mut line = String::new();
+        BufReader::new(&stream).read_line(&mut line)?;
+
+        let response = match IpcCommand::parse(&line) {
+            Ok(command) => {
+                let (reply, result) = mpsc::channel();
+                sender.send(IpcRequest { command, reply })?;
+                wake();
+                match result.recv_timeout(REPLY_TIMEOUT) {
+                    Ok(Ok(message)) => format!("ok {}", message),
+                    Ok(Err(message)) => format!("error {}", message),
+                    Err(_) => "error 응답 시간이 초과되었습니다".to_string(),
+                }
+            }
+            Err(e) => format!("error {}", e),
+        };
+
+        writeln!(stream, "{}", response)?;
+        Ok(())
+    }
+
+    pub fn send(path: &Path, command: &IpcCommand) -> Result<String> {
+        let mut stream = UnixStream::connect(path)
+            .map_err(|e| anyhow::anyhow!("실행 중인 프로그램에 연결할 수 없습니다: {}", e))?;
+        writeln!(stream, "{}", command.to_line())?;
+
+        let mut line = String::new();
+        BufReader::new(&stream).read_line(&mut line)?;
+
+        match line.trim_end().split_once(' ') {
+            Some(("ok", message)) => Ok(message.to_string()),
+            Some(("error", message)) => Err(anyhow::anyhow!("{}", message)),
+            _ => Err(anyhow::anyhow!("알 수 없는 응답: {}", line.trim_end())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_snooze_minutes() {
+        assert_eq!(
+            IpcCommand::parse("snooze 15\n").unwrap(),
+            IpcCommand::Snooze(15)
+        );
+        assert_eq!(
+            IpcCommand::parse(&IpcCommand::Snooze(3).to_line()).unwrap(),
+            IpcCommand::Snooze(3)
+        );
+        assert!(IpcCommand::parse("snooze 0").is_err());
+        assert!(IpcCommand::parse("snooze -1").is_err());
+        assert!(IpcCommand::parse("snooze").is_err());
+        assert!(IpcCommand::parse("stop").is_err());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn idle_client_does_not_block_other_clients() {
+        use std::os::unix::net::UnixStream;
+
+        let path = crate::test_util::temp_dir("ipc-idle").join("test.sock");
+        let (sender, requests) = mpsc::channel();
+        unix::listen(path.clone(), sender, || {}).unwrap();
+        std::thread::spawn(move || {
+            for request in requests {
+                let _ = request.reply.send(Ok(format!("{:?}", request.command)));
+            }
+        });
+
+        // 명령을 보내지 않고 연결만 유지
+        let _idle = UnixStream::connect(&path).unwrap();
+        assert_eq!(
+            unix::send(&path, &IpcCommand::Snooze(5)).unwrap(),
+            "Snooze(5)"
+        );
+    }
+}
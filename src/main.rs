@@ -1,10 +1,18 @@
 #![cfg_attr(all(not(debug_assertions), windows), windows_subsystem = "windows")] // Windows 릴리즈 빌드 시 콘솔 창 숨기기
 
+mod cli;
 mod config;
 mod gui;
+mod ipc;
+mod monitor;
 mod mouse;
+mod notify;
+#[cfg(test)]
+mod test_util;
 mod timer;
 
+use cli::{CliArgs, CliCommand};
+use config::{ConfigLocation, Overrides, ProfileStore, Settings};
 use eframe::egui;
 use gui::AutoMouseApp;
 
@@ -12,6 +20,82 @@ fn main() -> Result<(), eframe::Error> {
     // 로깅 초기화
     env_logger::init();
 
+    // 명령줄 인자 해석
+    let args = match CliArgs::parse(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("{}\n\n{}", e, cli::USAGE);
+            std::process::exit(2);
+        }
+    };
+
+    if args.help {
+        println!("{}", cli::USAGE);
+        return Ok(());
+    }
+
+    // 이후의 설정/프로필/소켓 경로는 모두 이 위치를 기준으로 함
+    match ConfigLocation::init(args.config.as_deref()) {
+        Ok(location) => log::info!("설정 위치: {}", location.describe()),
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(2);
+        }
+    }
+
+    if let Some(CliCommand::Snooze(minutes)) = &args.command {
+        match ipc::send_command(&ipc::IpcCommand::Snooze(*minutes)) {
+            Ok(message) => {
+                println!("{}", message);
+                std::process::exit(0);
+            }
+            Err(e) => {
+                eprintln!("{}", e);
+                std::process::exit(1);
+            }
+        }
+    }
+
+    if let Some(CliCommand::Config(command)) = &args.command {
+        if let Err(e) = cli::run_config(command, &args) {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        }
+        std::process::exit(0);
+    }
+
+    if let Some(CliCommand::Run(program)) = &args.command {
+        let settings = match load_profile(args.profile.as_deref()) {
+            Ok(settings) => settings,
+            Err(e) if args.profile.is_some() => {
+                eprintln!("{}", e);
+                std::process::exit(2);
+            }
+            Err(e) => {
+                log::warn!("설정 로드 실패, 기본값 사용: {}", e);
+                Settings::default()
+            }
+        };
+
+        // 환경 변수와 명령줄 값을 설정 파일 위에 덮어씀
+        let (overrides, mut warnings) = Overrides::from_env(args.overrides());
+        let (settings, override_warnings) = overrides.apply(&settings);
+        warnings.extend(override_warnings);
+        for warning in warnings {
+            log::warn!("{}", warning);
+        }
+
+        monitor::fullscreen::install_error_handler();
+        let code = match cli::run_wrapped(program, settings) {
+            Ok(code) => code,
+            Err(e) => {
+                eprintln!("{}", e);
+                1
+            }
+        };
+        std::process::exit(code);
+    }
+
     // GUI 애플리케이션 실행
     let options = eframe::NativeOptions {
         viewport: egui::ViewportBuilder::default()
@@ -24,6 +108,18 @@ fn main() -> Result<(), eframe::Error> {
     eframe::run_native(
         "Stay awake",
         options,
-        Box::new(|cc| Box::new(AutoMouseApp::new(cc))),
+        Box::new(|cc| Box::new(AutoMouseApp::new(cc, args))),
     )
 }
+
+/// 지정한 프로필(없으면 선택된 프로필)의 설정을 불러옴
+fn load_profile(requested: Option<&str>) -> anyhow::Result<Settings> {
+    let (store, mut warnings) = ProfileStore::load()?;
+    let (name, settings, profile_warnings) = store.load_selected(requested)?;
+    warnings.extend(profile_warnings);
+    for warning in warnings {
+        log::warn!("{}", warning);
+    }
+    log::info!("프로필: {}", name);
+    Ok(settings)
+}
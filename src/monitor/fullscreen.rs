@@ -0,0 +1,181 @@
+use anyhow::Result;
+
+/// 이 플랫폼에서 전체 화면 감지를 지원하는지 여부
+pub const SUPPORTED: bool = cfg!(unix);
+
+/// 현재 활성 창이 전체 화면(`_NET_WM_STATE_FULLSCREEN`) 상태인지 확인
+///
+/// 스레드마다 전용 X 디스플레이 연결을 한 번 열어 두고 계속 사용함
+pub fn is_fullscreen_active() -> Result<bool> {
+    #[cfg(unix)]
+    {
+        x11_detect::is_fullscreen_active()
+    }
+
+    #[cfg(not(unix))]
+    {
+        Err(anyhow::anyhow!("지원되지 않는 플랫폼입니다"))
+    }
+}
+
+/// 감지용 연결의 X 오류를 무시하는 처리기를 설치 (여러 번 호출해도 한 번만 설치됨)
+///
+/// `XSetErrorHandler`는 프로세스 전체에 적용되므로 조회마다 바꾸지 않고 시작 시 한 번만 설치하며,
+/// 다른 연결에서 난 오류는 이전 처리기로 넘김
+pub fn install_error_handler() {
+    #[cfg(unix)]
+    x11_detect::install_error_handler();
+}
+
+#[cfg(unix)]
+mod x11_detect {
+    use anyhow::Result;
+    use std::cell::{Cell, RefCell};
+    use std::ffi::CString;
+    use std::os::raw::{c_int, c_uchar, c_ulong};
+    use std::ptr;
+    use std::sync::{Once, OnceLock};
+    use x11::xlib::*;
+
+    type ErrorHandler = Option<unsafe extern "C" fn(*mut Display, *mut XErrorEvent) -> c_int>;
+
+    static INSTALL: Once = Once::new();
+    static PREVIOUS_HANDLER: OnceLock<ErrorHandler> = OnceLock::new();
+
+    thread_local! {
+        static CONNECTION: RefCell<Option<Connection>> = const { RefCell::new(None) };
+        // 오류 처리기에서 감지용 연결인지 구분하기 위한 값 (처리기는 오류가 난 스레드에서 호출됨)
+        static OWN_DISPLAY: Cell<*mut Display> = const { Cell::new(ptr::null_mut()) };
+    }
+
+    /// 감지 전용 X 디스플레이 연결과 미리 조회해 둔 아톰
+    struct Connection {
+        display: *mut Display,
+        active_atom: Atom,
+        state_atom: Atom,
+        fullscreen_atom: Atom,
+    }
+
+    impl Connection {
+        fn open() -> Result<Self> {
+            unsafe {
+                let display = XOpenDisplay(ptr::null());
+                if display.is_null() {
+                    return Err(anyhow::anyhow!("X11 디스플레이에 연결할 수 없습니다"));
+                }
+
+                let intern = |name: &str| {
+                    let name = CString::new(name).unwrap();
+                    XInternAtom(display, name.as_ptr(), False)
+                };
+                Ok(Self {
+                    display,
+                    active_atom: intern("_NET_ACTIVE_WINDOW"),
+                    state_atom: intern("_NET_WM_STATE"),
+                    fullscreen_atom: intern("_NET_WM_STATE_FULLSCREEN"),
+                })
+            }
+        }
+
+        fn is_fullscreen_active(&self) -> bool {
+            unsafe {
+                let root_window = XDefaultRootWindow(self.display);
+                let active = read_property(self.display, root_window, self.active_atom, XA_WINDOW)
+                    .first()
+                    .copied()
+                    .unwrap_or(0);
+
+                active != 0
+                    && read_property(self.display, active, self.state_atom, XA_ATOM)
+                        .contains(&self.fullscreen_atom)
+            }
+        }
+    }
+
+    impl Drop for Connection {
+        fn drop(&mut self) {
+            let _ = OWN_DISPLAY.try_with(|own| own.set(ptr::null_mut()));
+            unsafe {
+                XCloseDisplay(self.display);
+            }
+        }
+    }
+
+    pub fn is_fullscreen_active() -> Result<bool> {
+        install_error_handler();
+
+        CONNECTION.with(|connection| {
+            let mut connection = connection.borrow_mut();
+            if connection.is_none() {
+                let opened = Connection::open()?;
+                OWN_DISPLAY.with(|own| own.set(opened.display));
+                *connection = Some(opened);
+            }
+            Ok(connection.as_ref().unwrap().is_fullscreen_active())
+        })
+    }
+
+    pub fn install_error_handler() {
+        INSTALL.call_once(|| unsafe {
+            let previous = XSetErrorHandler(Some(handle_x_error));
+            let _ = PREVIOUS_HANDLER.set(previous);
+        });
+    }
+
+    /// 조회 중 활성 창이 닫히면 BadWindow 오류가 나는데, 기본 처리기는 프로세스를 종료하므로
+    /// 감지용 연결의 오류는 무시함 (실패한 조회는 빈 목록으로 처리됨)
+    unsafe extern "C" fn handle_x_error(display: *mut Display, event: *mut XErrorEvent) -> c_int {
+        let own = OWN_DISPLAY
+            .try_with(|own| own.get() == display)
+            .unwrap_or(false);
+        if own {
+            log::debug!("X 오류 무시: 코드 {}", (*event).error_code);
+            return 0;
+        }
+
+        match PREVIOUS_HANDLER.get().copied().flatten() {
+            Some(previous) => previous(display, event),
+            None => 0,
+        }
+    }
+
+    /// 32비트 형식의 창 속성 값을 읽어옴 (실패 시 빈 목록)
+    unsafe fn read_property(
+        display: *mut Display,
+        window: Window,
+        property: Atom,
+        property_type: Atom,
+    ) -> Vec<c_ulong> {
+        let mut actual_type = 0;
+        let mut actual_format: c_int = 0;
+        let mut nitems: c_ulong = 0;
+        let mut bytes_after: c_ulong = 0;
+        let mut data: *mut c_uchar = ptr::null_mut();
+
+        let status = XGetWindowProperty(
+            display,
+            window,
+            property,
+            0,
+            1024,
+            False,
+            property_type,
+            &mut actual_type,
+            &mut actual_format,
+            &mut nitems,
+            &mut bytes_after,
+            &mut data,
+        );
+
+        let mut values = Vec::new();
+        if status == Success as c_int && !data.is_null() {
+            // 형식이 32인 속성은 Xlib에서 long 배열로 전달됨
+            if actual_format == 32 {
+                let items = std::slice::from_raw_parts(data as *const c_ulong, nitems as usize);
+                values.extend_from_slice(items);
+            }
+            XFree(data as *mut _);
+        }
+        values
+    }
+}
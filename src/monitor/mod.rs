@@ -0,0 +1,11 @@
+pub mod fullscreen;
+pub mod power;
+pub mod process;
+pub mod resources;
+pub mod session;
+
+pub use fullscreen::is_fullscreen_active;
+pub use power::{read_power_state, PowerState};
+pub use process::{ProcessEvent, ProcessTarget, ProcessWatcher};
+pub use resources::{ResourceMonitor, ResourceThresholds, ResourceTrigger, TriggerEvent};
+pub use session::SessionLockWatcher;
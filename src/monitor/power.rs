@@ -0,0 +1,216 @@
+use crate::config::settings::PowerSettings;
+use anyhow::Result;
+use std::fs;
+use std::path::Path;
+
+/// sysfs에서 읽은 전원 상태
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PowerState {
+    /// AC 어댑터 연결 여부 (어댑터 정보가 없으면 None)
+    pub ac_online: Option<bool>,
+    /// 배터리 잔량 (%)
+    pub battery_capacity: Option<u8>,
+    /// 배터리 상태 (Charging, Discharging, Full 등)
+    pub battery_status: Option<String>,
+}
+
+impl PowerState {
+    pub fn has_battery(&self) -> bool {
+        self.battery_capacity.is_some() || self.battery_status.is_some()
+    }
+
+    /// 배터리로 동작 중인지 여부
+    pub fn on_battery(&self) -> bool {
+        match self.ac_online {
+            Some(online) => !online && self.has_battery(),
+            None => self.battery_status.as_deref() == Some("Discharging"),
+        }
+    }
+
+    /// 전원 설정에 따라 일시정지해야 하는 경우 그 사유
+    pub fn pause_reason(&self, power: &PowerSettings) -> Option<String> {
+        if !self.on_battery() {
+            return None;
+        }
+
+        if power.pause_on_battery {
+            return Some("배터리 사용 중".to_string());
+        }
+
+        match self.battery_capacity {
+            Some(capacity) if capacity < power.pause_below_percent => Some(format!(
+                "배터리 잔량 {}% (기준 {}% 미만)",
+                capacity, power.pause_below_percent
+            )),
+            _ => None,
+        }
+    }
+
+    pub fn describe(&self) -> String {
+        if !self.has_battery() {
+            return match self.ac_online {
+                Some(false) => "AC 연결 안 됨".to_string(),
+                _ => "AC 전원".to_string(),
+            };
+        }
+
+        let source = if self.on_battery() {
+            "배터리"
+        } else {
+            "AC 전원"
+        };
+        let capacity = self
+            .battery_capacity
+            .map(|capacity| format!(" {}%", capacity))
+            .unwrap_or_default();
+        let status = self
+            .battery_status
+            .as_deref()
+            .map(|status| format!(" ({})", status))
+            .unwrap_or_default();
+        format!("{}{}{}", source, capacity, status)
+    }
+}
+
+/// `power_supply` 디렉토리(보통 /sys/class/power_supply)에서 전원 상태를 읽음
+///
+/// 테스트를 위해 같은 구조의 임의 디렉토리를 지정할 수 있음
+pub fn read_power_state(dir: &Path) -> Result<PowerState> {
+    let mut entries: Vec<_> = fs::read_dir(dir)?.flatten().map(|e| e.path()).collect();
+    entries.sort();
+
+    let mut state = PowerState::default();
+    for supply in entries {
+        let read = |name: &str| {
+            fs::read_to_string(supply.join(name))
+                .ok()
+                .map(|value| value.trim().to_string())
+        };
+
+        match read("type").as_deref() {
+            Some("Mains") | Some("USB") => {
+                if let Some(online) = read("online") {
+                    let online = online == "1";
+                    state.ac_online = Some(state.ac_online.unwrap_or(false) || online);
+                }
+            }
+            Some("Battery") => {
+                // 무선 마우스 등 주변기기 배터리는 제외
+                if read("scope").as_deref() == Some("Device") || state.has_battery() {
+                    continue;
+                }
+                state.battery_capacity = read("capacity").and_then(|c| c.parse().ok());
+                state.battery_status = read("status");
+            }
+            _ => {}
+        }
+    }
+
+    Ok(state)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::temp_dir;
+
+    /// sysfs와 같은 구조로 전원 장치 하나를 만듦
+    fn supply(dir: &Path, name: &str, attributes: &[(&str, &str)]) {
+        let supply = dir.join(name);
+        fs::create_dir_all(&supply).unwrap();
+        for (attribute, value) in attributes {
+            fs::write(supply.join(attribute), format!("{}\n", value)).unwrap();
+        }
+    }
+
+    #[test]
+    fn ac_online_is_not_on_battery() {
+        let dir = temp_dir("power-ac");
+        supply(&dir, "AC", &[("type", "Mains"), ("online", "1")]);
+        supply(
+            &dir,
+            "BAT0",
+            &[
+                ("type", "Battery"),
+                ("capacity", "80"),
+                ("status", "Charging"),
+            ],
+        );
+
+        let state = read_power_state(&dir).unwrap();
+        assert_eq!(state.ac_online, Some(true));
+        assert_eq!(state.battery_capacity, Some(80));
+        assert!(!state.on_battery());
+        assert_eq!(state.describe(), "AC 전원 80% (Charging)");
+    }
+
+    #[test]
+    fn discharging_battery_without_adapter() {
+        let dir = temp_dir("power-discharging");
+        supply(
+            &dir,
+            "BAT0",
+            &[
+                ("type", "Battery"),
+                ("capacity", "42"),
+                ("status", "Discharging"),
+            ],
+        );
+
+        let state = read_power_state(&dir).unwrap();
+        assert_eq!(state.ac_online, None);
+        assert!(state.on_battery());
+        assert_eq!(state.describe(), "배터리 42% (Discharging)");
+    }
+
+    #[test]
+    fn device_scope_battery_is_ignored() {
+        let dir = temp_dir("power-device");
+        supply(&dir, "AC", &[("type", "Mains"), ("online", "0")]);
+        // 정렬 순서상 노트북 배터리보다 먼저 읽히는 무선 마우스 배터리
+        supply(
+            &dir,
+            "BAT-mouse",
+            &[("type", "Battery"), ("scope", "Device"), ("capacity", "5")],
+        );
+        supply(&dir, "BAT0", &[("type", "Battery"), ("capacity", "60")]);
+
+        let state = read_power_state(&dir).unwrap();
+        assert_eq!(state.battery_capacity, Some(60));
+        assert!(state.on_battery());
+    }
+
+    #[test]
+    fn pauses_below_percent_only_on_battery() {
+        let dir = temp_dir("power-threshold");
+        supply(&dir, "AC", &[("type", "Mains"), ("online", "0")]);
+        supply(&dir, "BAT0", &[("type", "Battery"), ("capacity", "15")]);
+        let mut state = read_power_state(&dir).unwrap();
+
+        let mut power = PowerSettings {
+            pause_below_percent: 15,
+            ..PowerSettings::default()
+        };
+        assert_eq!(state.pause_reason(&power), None);
+
+        power.pause_below_percent = 20;
+        assert_eq!(
+            state.pause_reason(&power).as_deref(),
+            Some("배터리 잔량 15% (기준 20% 미만)")
+        );
+
+        state.ac_online = Some(true);
+        assert_eq!(state.pause_reason(&power), None);
+
+        // 잔량 기준은 사용하지 않고 배터리 사용 중이면 항상 일시정지
+        state.ac_online = Some(false);
+        let power = PowerSettings {
+            pause_on_battery: true,
+            ..PowerSettings::default()
+        };
+        assert_eq!(
+            state.pause_reason(&power).as_deref(),
+            Some("배터리 사용 중")
+        );
+    }
+}
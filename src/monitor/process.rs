@@ -0,0 +1,196 @@
+use std::fs;
+use std::path::Path;
+
+/// 감시할 프로세스 지정 방식
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProcessTarget {
+    Pid(u32),
+    /// 프로세스 이름 패턴 (`*`, `?` 와일드카드 지원)
+    Pattern(String),
+}
+
+impl ProcessTarget {
+    /// 숫자만 있으면 PID, 그 외에는 이름 패턴으로 해석
+    pub fn parse(text: &str) -> Option<Self> {
+        let text = text.trim();
+        if text.is_empty() {
+            return None;
+        }
+        match text.parse::<u32>() {
+            Ok(pid) => Some(ProcessTarget::Pid(pid)),
+            Err(_) => Some(ProcessTarget::Pattern(text.to_string())),
+        }
+    }
+
+    pub fn describe(&self) -> String {
+        match self {
+            ProcessTarget::Pid(pid) => format!("PID {}", pid),
+            ProcessTarget::Pattern(pattern) => format!("'{}'", pattern),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProcessEvent {
+    Appeared(u32),
+    Exited(u32),
+}
+
+/// `/proc`를 주기적으로 확인하여 대상 프로세스의 시작/종료를 감지
+pub struct ProcessWatcher {
+    target: ProcessTarget,
+    current_pid: Option<u32>,
+}
+
+impl ProcessWatcher {
+    pub fn new(target: ProcessTarget) -> Self {
+        Self {
+            target,
+            current_pid: None,
+        }
+    }
+
+    pub fn target(&self) -> &ProcessTarget {
+        &self.target
+    }
+
+    pub fn current_pid(&self) -> Option<u32> {
+        self.current_pid
+    }
+
+    /// 상태가 바뀐 경우에만 이벤트를 반환
+    pub fn poll(&mut self) -> Option<ProcessEvent> {
+        let found = find_process(&self.target);
+
+        match (self.current_pid, found) {
+            (None, Some(pid)) => {
+                self.current_pid = Some(pid);
+                Some(ProcessEvent::Appeared(pid))
+            }
+            (Some(pid), None) => {
+                self.current_pid = None;
+                Some(ProcessEvent::Exited(pid))
+            }
+            (Some(old), Some(new)) if old != new && !process_exists(old) => {
+                // 같은 패턴의 다른 프로세스로 이어진 경우 계속 활성 상태 유지
+                self.current_pid = Some(new);
+                None
+            }
+            _ => None,
+        }
+    }
+}
+
+pub fn process_exists(pid: u32) -> bool {
+    Path::new("/proc").join(pid.to_string()).exists()
+}
+
+/// 대상과 일치하는 프로세스의 PID를 찾음 (자기 자신은 제외)
+pub fn find_process(target: &ProcessTarget) -> Option<u32> {
+    match target {
+        ProcessTarget::Pid(pid) => process_exists(*pid).then_some(*pid),
+        ProcessTarget::Pattern(pattern) => {
+            let own_pid = std::process::id();
+            let entries = fs::read_dir("/proc").ok()?;
+
+            entries
+                .flatten()
+                .filter_map(|entry| entry.file_name().to_str()?.parse::<u32>().ok())
+                .filter(|&pid| pid != own_pid)
+                .find(|&pid| process_matches(pid, pattern))
+        }
+    }
+}
+
+fn process_matches(pid: u32, pattern: &str) -> bool {
+    let proc_dir = Path::new("/proc").join(pid.to_string());
+
+    if let Ok(comm) = fs::read_to_string(proc_dir.join("comm")) {
+        if glob_match(pattern, comm.trim_end()) {
+            return true;
+        }
+    }
+
+    // comm은 15자로 잘리므로 명령줄도 함께 확인
+    if let Ok(cmdline) = fs::read(proc_dir.join("cmdline")) {
+        let args: Vec<String> = cmdline
+            .split(|&b| b == 0)
+            .filter(|arg| !arg.is_empty())
+            .map(|arg| String::from_utf8_lossy(arg).into_owned())
+            .collect();
+
+        if let Some(program) = args.first() {
+            let name = program.rsplit('/').next().unwrap_or(program);
+            if glob_match(pattern, name) || glob_match(pattern, &args.join(" ")) {
+                return true;
+            }
+        }
+    }
+
+    false
+}
+
+/// `*`(임의의 문자열)와 `?`(임의의 한 문자)를 지원하는 단순 와일드카드 비교
+pub fn glob_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+
+    let (mut p, mut t) = (0, 0);
+    let mut star: Option<(usize, usize)> = None;
+
+    while t < text.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == text[t]) {
+            p += 1;
+            t += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            star = Some((p, t));
+            p += 1;
+        } else if let Some((star_p, star_t)) = star {
+            // 마지막 `*`가 한 글자 더 소비하도록 되돌아감
+            p = star_p + 1;
+            t = star_t + 1;
+            star = Some((star_p, star_t + 1));
+        } else {
+            return false;
+        }
+    }
+
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_pid_or_pattern() {
+        assert_eq!(
+            ProcessTarget::parse(" 1234 "),
+            Some(ProcessTarget::Pid(1234))
+        );
+        assert_eq!(
+            ProcessTarget::parse("ffmpeg*"),
+            Some(ProcessTarget::Pattern("ffmpeg*".to_string()))
+        );
+        // u32 범위를 벗어난 숫자나 음수는 이름으로 취급
+        assert_eq!(
+            ProcessTarget::parse("-1"),
+            Some(ProcessTarget::Pattern("-1".to_string()))
+        );
+        assert_eq!(ProcessTarget::parse("   "), None);
+    }
+
+    #[test]
+    fn glob_wildcards() {
+        assert!(glob_match("cargo", "cargo"));
+        assert!(!glob_match("cargo", "cargo-build"));
+        assert!(glob_match("cargo*", "cargo-build"));
+        assert!(glob_match("*build", "cargo-build"));
+        assert!(glob_match("c?rgo", "cargo"));
+        assert!(!glob_match("c?rgo", "crgo"));
+        assert!(glob_match("*", ""));
+        assert!(glob_match("a*b*c", "a-b-b-c"));
+        assert!(!glob_match("a*b*c", "a-b-b-d"));
+        assert!(glob_match("내보내기*", "내보내기-작업"));
+    }
+}
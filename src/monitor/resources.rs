@@ -0,0 +1,343 @@
+use crate::timer::Clock;
+use anyhow::Result;
+use std::collections::VecDeque;
+use std::fs;
+use std::path::Path;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+const SECTOR_SIZE: u64 = 512;
+
+/// 누적 카운터 값 (한 시점의 측정값)
+#[derive(Debug, Clone, Copy)]
+struct Counters {
+    at: Instant,
+    cpu_busy: u64,
+    cpu_total: u64,
+    disk_bytes: u64,
+    net_bytes: u64,
+}
+
+/// 측정 구간 동안의 평균 사용량
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ResourceReadings {
+    pub cpu_percent: f64,
+    pub disk_bytes_per_sec: f64,
+    pub net_bytes_per_sec: f64,
+}
+
+/// `/proc/stat`, `/proc/diskstats`, `/proc/net/dev`를 주기적으로 읽어 평균 부하를 계산
+pub struct ResourceMonitor {
+    samples: VecDeque<Counters>,
+    window: Duration,
+}
+
+impl ResourceMonitor {
+    pub fn new(window: Duration) -> Self {
+        Self {
+            samples: VecDeque::new(),
+            window,
+        }
+    }
+
+    pub fn set_window(&mut self, window: Duration) {
+        self.window = window;
+    }
+
+    pub fn sample(&mut self) -> Result<()> {
+        let (cpu_busy, cpu_total) = read_cpu_times()?;
+        let counters = Counters {
+            at: Instant::now(),
+            cpu_busy,
+            cpu_total,
+            disk_bytes: read_disk_bytes()?,
+            net_bytes: read_net_bytes()?,
+        };
+        self.samples.push_back(counters);
+
+        // 측정 구간보다 오래된 값 제거 (구간 시작점 하나는 남김)
+        while self.samples.len() > 2
+            && counters.at.duration_since(self.samples[1].at) >= self.window
+        {
+            self.samples.pop_front();
+        }
+        Ok(())
+    }
+
+    /// 측정값이 두 개 이상 모인 경우 구간 평균을 반환
+    pub fn readings(&self) -> Option<ResourceReadings> {
+        let first = self.samples.front()?;
+        let last = self.samples.back()?;
+        let seconds = last.at.duration_since(first.at).as_secs_f64();
+        if seconds <= 0.0 {
+            return None;
+        }
+
+        let cpu_total = last.cpu_total.saturating_sub(first.cpu_total);
+        let cpu_busy = last.cpu_busy.saturating_sub(first.cpu_busy);
+        let cpu_percent = if cpu_total > 0 {
+            cpu_busy as f64 * 100.0 / cpu_total as f64
+        } else {
+            0.0
+        };
+
+        Some(ResourceReadings {
+            cpu_percent,
+            disk_bytes_per_sec: last.disk_bytes.saturating_sub(first.disk_bytes) as f64 / seconds,
+            net_bytes_per_sec: last.net_bytes.saturating_sub(first.net_bytes) as f64 / seconds,
+        })
+    }
+}
+
+/// 부하 임계값 (0 이하인 항목은 사용하지 않음)
+#[derive(Debug, Clone, Copy)]
+pub struct ResourceThresholds {
+    pub cpu_percent: f64,
+    pub disk_bytes_per_sec: f64,
+    pub net_bytes_per_sec: f64,
+}
+
+impl ResourceThresholds {
+    pub fn exceeded_by(&self, readings: &ResourceReadings) -> bool {
+        let exceeds = |threshold: f64, value: f64| threshold > 0.0 && value > threshold;
+
+        exceeds(self.cpu_percent, readings.cpu_percent)
+            || exceeds(self.disk_bytes_per_sec, readings.disk_bytes_per_sec)
+            || exceeds(self.net_bytes_per_sec, readings.net_bytes_per_sec)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TriggerEvent {
+    Activate,
+    Deactivate,
+}
+
+/// 임계값을 넘으면 활성화하고, 부하가 낮은 상태가 대기 시간 동안 이어지면 비활성화
+pub struct ResourceTrigger {
+    active: bool,
+    last_busy: Option<Instant>,
+    clock: Arc<dyn Clock>,
+}
+
+impl ResourceTrigger {
+    pub fn new(clock: Arc<dyn Clock>) -> Self {
+        Self {
+            active: false,
+            last_busy: None,
+            clock,
+        }
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    pub fn reset(&mut self) {
+        self.active = false;
+        self.last_busy = None;
+    }
+
+    pub fn evaluate(
+        &mut self,
+        readings: &ResourceReadings,
+        thresholds: &ResourceThresholds,
+        cooldown: Duration,
+    ) -> Option<TriggerEvent> {
+        let now = self.clock.instant();
+
+        if thresholds.exceeded_by(readings) {
+            self.last_busy = Some(now);
+            if !self.active {
+                self.active = true;
+                return Some(TriggerEvent::Activate);
+            }
+            return None;
+        }
+
+        let idle_long_enough =
+            !matches!(self.last_busy, Some(busy) if now.duration_since(busy) < cooldown);
+
+        if self.active && idle_long_enough {
+            self.active = false;
+            return Some(TriggerEvent::Deactivate);
+        }
+        None
+    }
+}
+
+/// (사용 시간, 전체 시간) 단위: jiffies
+fn read_cpu_times() -> Result<(u64, u64)> {
+    let content = fs::read_to_string("/proc/stat")?;
+    let line = content
+        .lines()
+        .find(|line| line.starts_with("cpu "))
+        .ok_or_else(|| anyhow::anyhow!("/proc/stat에서 CPU 정보를 찾을 수 없습니다"))?;
+
+    // user nice system idle iowait irq softirq steal (guest는 user에 이미 포함됨)
+    let values: Vec<u64> = line
+        .split_whitespace()
+        .skip(1)
+        .take(8)
+        .map(|value| value.parse().unwrap_or(0))
+        .collect();
+
+    let total: u64 = values.iter().sum();
+    let idle = values.get(3).copied().unwrap_or(0) + values.get(4).copied().unwrap_or(0);
+    Ok((total.saturating_sub(idle), total))
+}
+
+/// 모든 디스크의 누적 읽기/쓰기 바이트 (파티션은 중복되므로 제외)
+fn read_disk_bytes() -> Result<u64> {
+    let content = fs::read_to_string("/proc/diskstats")?;
+    let mut total = 0;
+
+    for line in content.lines() {
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        if fields.len() < 10 {
+            continue;
+        }
+
+        let name = fields[2];
+        let is_whole_disk = Path::new("/sys/block").join(name).exists();
+        if !is_whole_disk || name.starts_with("loop") || name.starts_with("ram") {
+            continue;
+        }
+
+        let sectors_read: u64 = fields[5].parse().unwrap_or(0);
+        let sectors_written: u64 = fields[9].parse().unwrap_or(0);
+        total += (sectors_read + sectors_written) * SECTOR_SIZE;
+    }
+
+    Ok(total)
+}
+
+/// 루프백을 제외한 모든 인터페이스의 누적 송수신 바이트
+fn read_net_bytes() -> Result<u64> {
+    let content = fs::read_to_string("/proc/net/dev")?;
+    let mut total = 0;
+
+    // 처음 두 줄은 헤더
+    for line in content.lines().skip(2) {
+        let Some((name, stats)) = line.split_once(':') else {
+            continue;
+        };
+        if name.trim() == "lo" {
+            continue;
+        }
+
+        let fields: Vec<u64> = stats
+            .split_whitespace()
+            .map(|value| value.parse().unwrap_or(0))
+            .collect();
+        let received = fields.first().copied().unwrap_or(0);
+        let transmitted = fields.get(8).copied().unwrap_or(0);
+        total += received + transmitted;
+    }
+
+    Ok(total)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::timer::clock::ManualClock;
+
+    const COOLDOWN: Duration = Duration::from_secs(60);
+
+    fn manual_trigger() -> (Arc<ManualClock>, ResourceTrigger) {
+        let clock = Arc::new(ManualClock::monday_morning());
+        let trigger = ResourceTrigger::new(clock.clone());
+        (clock, trigger)
+    }
+
+    fn cpu(percent: f64) -> ResourceReadings {
+        ResourceReadings {
+            cpu_percent: percent,
+            ..ResourceReadings::default()
+        }
+    }
+
+    fn cpu_threshold(percent: f64) -> ResourceThresholds {
+        ResourceThresholds {
+            cpu_percent: percent,
+            disk_bytes_per_sec: 0.0,
+            net_bytes_per_sec: 0.0,
+        }
+    }
+
+    #[test]
+    fn thresholds_must_be_exceeded_and_zero_is_disabled() {
+        let thresholds = cpu_threshold(50.0);
+        assert!(!thresholds.exceeded_by(&cpu(50.0)));
+        assert!(thresholds.exceeded_by(&cpu(50.1)));
+
+        let disabled = cpu_threshold(0.0);
+        assert!(!disabled.exceeded_by(&cpu(100.0)));
+
+        let network = ResourceThresholds {
+            net_bytes_per_sec: 1000.0,
+            ..disabled
+        };
+        let busy_network = ResourceReadings {
+            net_bytes_per_sec: 2000.0,
+            ..ResourceReadings::default()
+        };
+        assert!(network.exceeded_by(&busy_network));
+    }
+
+    #[test]
+    fn deactivates_after_cooldown_of_low_load() {
+        let thresholds = cpu_threshold(50.0);
+        let (clock, mut trigger) = manual_trigger();
+
+        assert_eq!(trigger.evaluate(&cpu(10.0), &thresholds, COOLDOWN), None);
+        assert_eq!(
+            trigger.evaluate(&cpu(80.0), &thresholds, COOLDOWN),
+            Some(TriggerEvent::Activate)
+        );
+        assert_eq!(trigger.evaluate(&cpu(90.0), &thresholds, COOLDOWN), None);
+
+        clock.advance(Duration::from_secs(59));
+        assert_eq!(trigger.evaluate(&cpu(10.0), &thresholds, COOLDOWN), None);
+        assert!(trigger.is_active());
+
+        clock.advance(Duration::from_secs(1));
+        assert_eq!(
+            trigger.evaluate(&cpu(10.0), &thresholds, COOLDOWN),
+            Some(TriggerEvent::Deactivate)
+        );
+        assert!(!trigger.is_active());
+    }
+
+    #[test]
+    fn busy_reading_restarts_cooldown() {
+        let thresholds = cpu_threshold(50.0);
+        let (clock, mut trigger) = manual_trigger();
+        trigger.evaluate(&cpu(80.0), &thresholds, COOLDOWN);
+
+        clock.advance(Duration::from_secs(50));
+        assert_eq!(trigger.evaluate(&cpu(80.0), &thresholds, COOLDOWN), None);
+
+        // 마지막으로 부하가 높았던 시점부터 다시 센다
+        clock.advance(Duration::from_secs(50));
+        assert_eq!(trigger.evaluate(&cpu(10.0), &thresholds, COOLDOWN), None);
+
+        clock.advance(Duration::from_secs(10));
+        assert_eq!(
+            trigger.evaluate(&cpu(10.0), &thresholds, COOLDOWN),
+            Some(TriggerEvent::Deactivate)
+        );
+    }
+
+    #[test]
+    fn reset_forgets_activation() {
+        let thresholds = cpu_threshold(50.0);
+        let (_clock, mut trigger) = manual_trigger();
+        trigger.evaluate(&cpu(80.0), &thresholds, COOLDOWN);
+
+        trigger.reset();
+        assert!(!trigger.is_active());
+        assert_eq!(trigger.evaluate(&cpu(10.0), &thresholds, COOLDOWN), None);
+    }
+}
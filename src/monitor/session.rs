@@ -0,0 +1,165 @@
+use anyhow::Result;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// logind 세션의 잠금 상태를 D-Bus로 감시
+///
+/// `LockedHint` 속성 변경과 `Lock`/`Unlock` 시그널을 백그라운드 스레드에서 받아 반영함
+pub struct SessionLockWatcher {
+    locked: Arc<AtomicBool>,
+    available: Arc<AtomicBool>,
+}
+
+impl SessionLockWatcher {
+    pub fn spawn() -> Self {
+        let watcher = Self {
+            locked: Arc::new(AtomicBool::new(false)),
+            available: Arc::new(AtomicBool::new(false)),
+        };
+
+        #[cfg(unix)]
+        {
+            let locked = Arc::clone(&watcher.locked);
+            let available = Arc::clone(&watcher.available);
+            std::thread::spawn(move || {
+                if let Err(e) = logind::watch_lock(locked, available) {
+                    log::warn!("세션 잠금 감시 실패: {}", e);
+                }
+            });
+        }
+
+        watcher
+    }
+
+    pub fn is_locked(&self) -> bool {
+        self.locked.load(Ordering::Relaxed)
+    }
+
+    /// D-Bus 연결에 성공하여 잠금 상태를 알 수 있는지 여부
+    pub fn is_available(&self) -> bool {
+        self.available.load(Ordering::Relaxed)
+    }
+}
+
+/// logind를 통해 현재 세션을 잠금
+pub fn lock_session() -> Result<()> {
+    #[cfg(unix)]
+    {
+        logind::lock_session()
+    }
+
+    #[cfg(not(unix))]
+    {
+        Err(anyhow::anyhow!("지원되지 않는 플랫폼입니다"))
+    }
+}
+
+/// logind를 통해 시스템을 절전 모드로 전환
+pub fn suspend() -> Result<()> {
+    #[cfg(unix)]
+    {
+        logind::suspend()
+    }
+
+    #[cfg(not(unix))]
+    {
+        Err(anyhow::anyhow!("지원되지 않는 플랫폼입니다"))
+    }
+}
+
+#[cfg(unix)]
+mod logind {
+    use anyhow::Result;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+    use zbus::blocking::{Connection, Proxy};
+    use zbus::zvariant::OwnedObjectPath;
+
+    const DESTINATION: &str = "org.freedesktop.login1";
+    const MANAGER_PATH: &str = "/org/freedesktop/login1";
+    const MANAGER_INTERFACE: &str = "org.freedesktop.login1.Manager";
+    const SESSION_INTERFACE: &str = "org.freedesktop.login1.Session";
+
+    /// 현재 프로그램이 속한 세션의 객체 경로
+    fn session_path(connection: &Connection) -> Result<OwnedObjectPath> {
+        let manager = Proxy::new(connection, DESTINATION, MANAGER_PATH, MANAGER_INTERFACE)?;
+
+        if let Ok(id) = std::env::var("XDG_SESSION_ID") {
+            if let Ok(path) = manager.call("GetSession", &(id.as_str(),)) {
+                return Ok(path);
+            }
+        }
+
+        match manager.call("GetSessionByPID", &(std::process::id(),)) {
+            Ok(path) => Ok(path),
+            // 세션에 속하지 않은 경우 logind가 호출자 세션을 찾도록 함
+            Err(_) => Ok(OwnedObjectPath::try_from(
+                "/org/freedesktop/login1/session/auto",
+            )?),
+        }
+    }
+
+    fn session_proxy(connection: &Connection) -> Result<Proxy<'static>> {
+        let path = session_path(connection)?;
+        Ok(Proxy::new(
+            connection,
+            DESTINATION,
+            path,
+            SESSION_INTERFACE,
+        )?)
+    }
+
+    pub fn lock_session() -> Result<()> {
+        let connection = Connection::system()?;
+        session_proxy(&connection)?.call_method("Lock", &())?;
+        Ok(())
+    }
+
+    pub fn suspend() -> Result<()> {
+        let connection = Connection::system()?;
+        let manager = Proxy::new(&connection, DESTINATION, MANAGER_PATH, MANAGER_INTERFACE)?;
+        // 권한이 필요하면 polkit 인증 창을 띄우도록 interactive = true
+        manager.call_method("Suspend", &(true,))?;
+        Ok(())
+    }
+
+    pub fn watch_lock(locked: Arc<AtomicBool>, available: Arc<AtomicBool>) -> Result<()> {
+        let connection = Connection::system()?;
+        let session = session_proxy(&connection)?;
+
+        let hint: bool = session.get_property("LockedHint")?;
+        locked.store(hint, Ordering::Relaxed);
+        available.store(true, Ordering::Relaxed);
+        log::info!("세션 잠금 감시 시작 (현재 잠김: {})", hint);
+
+        // LockedHint 속성 변경은 별도 스레드에서 감시
+        let hint_session = session.clone();
+        let hint_locked = Arc::clone(&locked);
+        std::thread::spawn(move || {
+            for change in hint_session.receive_property_changed::<bool>("LockedHint") {
+                if let Ok(hint) = change.get() {
+                    log::info!("세션 잠금 상태 변경: {}", hint);
+                    hint_locked.store(hint, Ordering::Relaxed);
+                }
+            }
+        });
+
+        for message in session.receive_all_signals()? {
+            let header = message.header();
+            match header.member().map(|member| member.as_str()) {
+                Some("Lock") => {
+                    log::info!("세션 잠금 신호 수신");
+                    locked.store(true, Ordering::Relaxed);
+                }
+                Some("Unlock") => {
+                    log::info!("세션 잠금 해제 신호 수신");
+                    locked.store(false, Ordering::Relaxed);
+                }
+                _ => {}
+            }
+        }
+
+        available.store(false, Ordering::Relaxed);
+        Ok(())
+    }
+}
@@ -1,23 +1,40 @@
-use crate::config::Settings;
+use crate::config::{ActivityKind, FullscreenAction, Settings};
+use crate::monitor;
+use crate::timer::suspend::format_duration;
+use crate::timer::{Clock, SuspendDetector, SystemClock};
 use anyhow::Result;
 use chrono::{DateTime, Utc};
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::Arc;
 use std::thread;
 use std::time::Duration;
 
+// 중지/일시정지/절전 복귀를 빨리 알아차리도록 대기를 나누는 단위
+const SLEEP_STEP: Duration = Duration::from_secs(1);
+
 pub struct MouseController {
     is_running: Arc<AtomicBool>,
+    is_paused: Arc<AtomicBool>,
+    // 동작 간격 재정의 값 (f64 비트, 0이면 설정값 사용)
+    interval_override: Arc<AtomicU64>,
     total_moves: u64,
     last_move_time: DateTime<Utc>,
+    clock: Arc<dyn Clock>,
 }
 
 impl MouseController {
     pub fn new() -> Self {
+        Self::with_clock(Arc::new(SystemClock))
+    }
+
+    pub fn with_clock(clock: Arc<dyn Clock>) -> Self {
         Self {
             is_running: Arc::new(AtomicBool::new(false)),
+            is_paused: Arc::new(AtomicBool::new(false)),
+            interval_override: Arc::new(AtomicU64::new(0)),
             total_moves: 0,
             last_move_time: Utc::now(),
+            clock,
         }
     }
 
@@ -26,11 +43,33 @@ impl MouseController {
             return;
         }
 
-        self.is_running.store(true, Ordering::Relaxed);
+        // 실행마다 새 플래그를 사용하여, 중지 직후 다시 시작해도
+        // 아직 대기 중인 이전 동작 스레드가 함께 살아나지 않도록 함
+        self.is_running = Arc::new(AtomicBool::new(true));
         let is_running = Arc::clone(&self.is_running);
+        let is_paused = Arc::clone(&self.is_paused);
+        let interval_override = Arc::clone(&self.interval_override);
+        let clock = Arc::clone(&self.clock);
 
         thread::spawn(move || {
-            Self::mouse_worker(is_running, settings);
+            let interval = settings.interval_seconds;
+            Self::mouse_worker(
+                &is_running,
+                &is_paused,
+                &interval_override,
+                interval,
+                clock.as_ref(),
+                || {
+                    let Some(kind) = Self::resolve_activity_kind(&settings) else {
+                        return;
+                    };
+
+                    // 설정된 동작 수행
+                    if let Err(e) = Self::perform_activity_internal(kind, settings.move_distance) {
+                        eprintln!("{} 실패: {}", kind.label(), e);
+                    }
+                },
+            );
         });
     }
 
@@ -38,6 +77,25 @@ impl MouseController {
         self.is_running.store(false, Ordering::Relaxed);
     }
 
+    /// 동작 중이면 새 설정으로 동작 스레드를 다시 시작
+    pub fn restart(&mut self, settings: Settings) {
+        if self.is_running.load(Ordering::Relaxed) {
+            self.stop();
+            self.start(settings);
+        }
+    }
+
+    /// 동작 스레드는 유지한 채 동작만 멈추거나 다시 시작
+    pub fn set_paused(&self, paused: bool) {
+        self.is_paused.store(paused, Ordering::Relaxed);
+    }
+
+    /// 설정의 동작 간격 대신 사용할 간격 지정 (None이면 설정값 사용)
+    pub fn set_interval_override(&self, seconds: Option<f64>) {
+        let bits = seconds.filter(|s| *s > 0.0).map_or(0, f64::to_bits);
+        self.interval_override.store(bits, Ordering::Relaxed);
+    }
+
     pub fn move_mouse(&mut self, distance: i32) {
         if let Err(e) = self.perform_mouse_move(distance) {
             eprintln!("마우스 이동 실패: {}", e);
@@ -56,25 +114,132 @@ impl MouseController {
         self.last_move_time
     }
 
-    fn mouse_worker(is_running: Arc<AtomicBool>, settings: Settings) {
+    /// 중지될 때까지 동작 간격마다 `tick`을 호출 (일시정지 중에는 건너뜀)
+    ///
+    /// 절전에서 깨어나면 그동안 놓친 동작을 몰아서 수행하지 않고,
+    /// 깨어난 시점부터 동작 간격을 다시 셈
+    fn mouse_worker<F: FnMut()>(
+        is_running: &AtomicBool,
+        is_paused: &AtomicBool,
+        interval_override: &AtomicU64,
+        interval_seconds: f64,
+        clock: &dyn Clock,
+        mut tick: F,
+    ) {
+        let interval = || match interval_override.load(Ordering::Relaxed) {
+            0 => Self::interval_duration(interval_seconds),
+            bits => Self::interval_duration(f64::from_bits(bits)),
+        };
+        let mut suspend = SuspendDetector::new(clock);
+        let mut next_tick = clock.instant() + interval();
+
         while is_running.load(Ordering::Relaxed) {
-            thread::sleep(Duration::from_secs_f64(settings.interval_seconds));
+            let now = clock.instant();
+            if now < next_tick {
+                clock.sleep((next_tick - now).min(SLEEP_STEP));
+
+                if let Some(gap) = suspend.poll(clock) {
+                    log::info!(
+                        "절전 후 재개 ({}), 놓친 동작은 건너뜀",
+                        format_duration(gap)
+                    );
+                    next_tick = clock.instant() + interval();
+                }
+                continue;
+            }
 
-            if !is_running.load(Ordering::Relaxed) {
-                break;
+            next_tick = now + interval();
+            if is_paused.load(Ordering::Relaxed) {
+                continue;
             }
 
-            // 마우스 이동 수행
-            if let Err(e) = Self::perform_mouse_move_internal(settings.move_distance) {
-                eprintln!("마우스 이동 실패: {}", e);
+            tick();
+        }
+    }
+
+    /// 동작 간격을 Duration으로 변환 (음수, 0, NaN 등 잘못된 값은 기본 간격 사용)
+    fn interval_duration(seconds: f64) -> Duration {
+        Duration::try_from_secs_f64(seconds)
+            .ok()
+            .filter(|interval| !interval.is_zero())
+            .unwrap_or_else(|| {
+                log::warn!("잘못된 동작 간격 {}초, 기본 간격 사용", seconds);
+                Duration::from_secs_f64(Settings::default().interval_seconds)
+            })
+    }
+
+    /// 전체 화면 여부에 따라 이번에 수행할 동작을 결정 (None이면 건너뜀)
+    fn resolve_activity_kind(settings: &Settings) -> Option<ActivityKind> {
+        let kind = settings.activity_kind;
+        if !settings.fullscreen.detect {
+            return Some(kind);
+        }
+
+        match monitor::is_fullscreen_active() {
+            Ok(true) => match settings.fullscreen.action_for(kind) {
+                FullscreenAction::Keep => Some(kind),
+                FullscreenAction::NonVisual => Some(ActivityKind::ScreenSaverReset),
+                FullscreenAction::Skip => None,
+            },
+            Ok(false) => Some(kind),
+            Err(e) => {
+                log::debug!("전체 화면 감지 실패: {}", e);
+                Some(kind)
             }
         }
     }
 
+    fn perform_activity_internal(kind: ActivityKind, distance: i32) -> Result<()> {
+        match kind {
+            ActivityKind::MouseMove => Self::perform_mouse_move_internal(distance),
+            ActivityKind::ScreenSaverReset => Self::reset_screensaver_internal(),
+        }
+    }
+
     fn perform_mouse_move(&self, distance: i32) -> Result<()> {
         Self::perform_mouse_move_internal(distance)
     }
 
+    fn reset_screensaver_internal() -> Result<()> {
+        #[cfg(windows)]
+        {
+            use winapi::um::winbase::SetThreadExecutionState;
+            use winapi::um::winnt::{ES_DISPLAY_REQUIRED, ES_SYSTEM_REQUIRED};
+
+            unsafe {
+                // 디스플레이/시스템 유휴 타이머 초기화
+                SetThreadExecutionState(ES_DISPLAY_REQUIRED | ES_SYSTEM_REQUIRED);
+            }
+        }
+
+        #[cfg(unix)]
+        {
+            use std::ptr;
+            use x11::xlib::*;
+
+            unsafe {
+                let display = XOpenDisplay(ptr::null());
+                if display.is_null() {
+                    return Err(anyhow::anyhow!("X11 디스플레이에 연결할 수 없습니다"));
+                }
+
+                // 화면보호기 유휴 타이머 초기화 (포인터는 움직이지 않음)
+                XResetScreenSaver(display);
+                XFlush(display);
+
+                XCloseDisplay(display);
+            }
+        }
+
+        #[cfg(not(any(windows, unix)))]
+        {
+            // 지원되지 않는 플랫폼
+            return Err(anyhow::anyhow!("지원되지 않는 플랫폼입니다"));
+        }
+
+        Ok(())
+    }
+
     fn perform_mouse_move_internal(distance: i32) -> Result<()> {
         #[cfg(windows)]
         {
@@ -156,3 +321,137 @@ impl MouseController {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::timer::clock::ManualClock;
+    use crate::timer::WallClock;
+    use chrono::Local;
+
+    const HOUR: Duration = Duration::from_secs(60 * 60);
+
+    #[test]
+    fn worker_ticks_every_interval_for_hours() {
+        let clock = ManualClock::monday_morning();
+        let is_running = AtomicBool::new(true);
+        let is_paused = AtomicBool::new(false);
+        let interval_override = AtomicU64::new(0);
+
+        let mut ticks = 0;
+        MouseController::mouse_worker(
+            &is_running,
+            &is_paused,
+            &interval_override,
+            30.0,
+            &clock,
+            || {
+                ticks += 1;
+                if clock.elapsed() >= 8 * HOUR {
+                    is_running.store(false, Ordering::Relaxed);
+                }
+            },
+        );
+
+        assert_eq!(ticks, 8 * 60 * 2);
+        assert_eq!(clock.elapsed(), 8 * HOUR);
+    }
+
+    #[test]
+    fn worker_skips_ticks_missed_during_suspend() {
+        let clock = ManualClock::monday_morning();
+        let is_running = AtomicBool::new(true);
+        let is_paused = AtomicBool::new(false);
+        let interval_override = AtomicU64::new(0);
+
+        let mut ticks = Vec::new();
+        MouseController::mouse_worker(
+            &is_running,
+            &is_paused,
+            &interval_override,
+            30.0,
+            &clock,
+            || {
+                let elapsed = clock.elapsed();
+                ticks.push(elapsed);
+                if elapsed == HOUR {
+                    clock.suspend(2 * HOUR);
+                } else if elapsed >= 2 * HOUR {
+                    is_running.store(false, Ordering::Relaxed);
+                }
+            },
+        );
+
+        // 절전 중 놓친 240회는 몰아서 수행하지 않고, 깨어난 뒤 간격을 다시 셈
+        assert_eq!(ticks[119], HOUR);
+        assert_eq!(ticks[120], HOUR + Duration::from_secs(31));
+        assert_eq!(ticks.len(), 240);
+        assert_eq!(clock.boottime(), 4 * HOUR + Duration::from_secs(1));
+    }
+
+    /// 대기할 때마다 경과 시간에 따라 일시정지/간격/중지 상태를 바꾸는 시계
+    struct ScriptedClock<'a> {
+        inner: ManualClock,
+        is_running: &'a AtomicBool,
+        is_paused: &'a AtomicBool,
+        interval_override: &'a AtomicU64,
+    }
+
+    impl WallClock for ScriptedClock<'_> {
+        fn now(&self) -> chrono::DateTime<Local> {
+            self.inner.now()
+        }
+    }
+
+    impl Clock for ScriptedClock<'_> {
+        fn instant(&self) -> std::time::Instant {
+            self.inner.instant()
+        }
+
+        fn boottime(&self) -> Duration {
+            self.inner.boottime()
+        }
+
+        // 처음 1시간은 60초 간격, 다음 1시간은 일시정지, 마지막 1시간은 120초 간격
+        fn sleep(&self, duration: Duration) {
+            self.inner.sleep(duration);
+            let elapsed = self.inner.elapsed();
+            if elapsed >= HOUR {
+                self.interval_override
+                    .store(120f64.to_bits(), Ordering::Relaxed);
+            }
+            self.is_paused
+                .store(elapsed >= HOUR && elapsed < 2 * HOUR, Ordering::Relaxed);
+            if elapsed >= 3 * HOUR {
+                self.is_running.store(false, Ordering::Relaxed);
+            }
+        }
+    }
+
+    #[test]
+    fn worker_skips_ticks_while_paused_and_uses_override() {
+        let is_running = AtomicBool::new(true);
+        let is_paused = AtomicBool::new(false);
+        let interval_override = AtomicU64::new(0);
+        let clock = ScriptedClock {
+            inner: ManualClock::monday_morning(),
+            is_running: &is_running,
+            is_paused: &is_paused,
+            interval_override: &interval_override,
+        };
+
+        let mut ticks = 0;
+        MouseController::mouse_worker(
+            &is_running,
+            &is_paused,
+            &interval_override,
+            60.0,
+            &clock,
+            || ticks += 1,
+        );
+
+        // 60초 간격 59회 (1시간 시점부터 일시정지) + 120초 간격 30회 (3시간 시점에 중지)
+        assert_eq!(ticks, 89);
+        assert_eq!(clock.inner.elapsed(), 3 * HOUR);
+    }
+}
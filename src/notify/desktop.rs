@@ -0,0 +1,61 @@
+/// 데스크톱 알림을 보내고, 필요하면 알림음을 재생
+///
+/// 알림 실패는 동작에 영향을 주지 않도록 로그만 남김
+pub fn notify(summary: &str, body: &str, sound: bool) {
+    if let Err(e) = send_notification(summary, body) {
+        log::warn!("알림 전송 실패: {}", e);
+    }
+
+    if sound {
+        play_sound();
+    }
+}
+
+pub fn send_notification(summary: &str, body: &str) -> anyhow::Result<()> {
+    #[cfg(unix)]
+    {
+        use std::process::Command;
+
+        let mut child = Command::new("notify-send")
+            .arg("--app-name=Stay awake")
+            .arg(summary)
+            .arg(body)
+            .spawn()?;
+
+        // 좀비 프로세스가 남지 않도록 별도 스레드에서 종료를 기다림
+        std::thread::spawn(move || match child.wait() {
+            Ok(status) if !status.success() => log::warn!("notify-send 실패 ({})", status),
+            Ok(_) => {}
+            Err(e) => log::warn!("notify-send 대기 실패: {}", e),
+        });
+    }
+
+    #[cfg(windows)]
+    {
+        // Windows에서는 별도 알림 대신 로그로 대체
+        log::info!("{}: {}", summary, body);
+    }
+
+    Ok(())
+}
+
+fn play_sound() {
+    #[cfg(windows)]
+    {
+        use winapi::um::winuser::{MessageBeep, MB_OK};
+
+        unsafe {
+            MessageBeep(MB_OK);
+        }
+    }
+
+    #[cfg(not(windows))]
+    {
+        use std::io::Write;
+
+        // 터미널 벨 문자로 알림음 재생
+        let mut stdout = std::io::stdout();
+        let _ = stdout.write_all(b"\x07");
+        let _ = stdout.flush();
+    }
+}
@@ -0,0 +1,3 @@
+pub mod desktop;
+
+pub use desktop::{notify, send_notification};
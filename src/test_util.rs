@@ -0,0 +1,9 @@
+use std::path::PathBuf;
+
+/// 테스트마다 비어 있는 임시 디렉토리 (`auto-mouse-<name>-<pid>`)
+pub fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("auto-mouse-{}-{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
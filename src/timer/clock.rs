@@ -0,0 +1,130 @@
+use chrono::{DateTime, Local};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// 현재 벽시계 시각을 제공 (테스트에서는 임의의 시각으로 대체)
+pub trait WallClock {
+    fn now(&self) -> DateTime<Local>;
+}
+
+/// 타이머와 동작 스레드가 사용하는 시계 (경과 시간 측정과 대기)
+///
+/// 테스트에서는 `ManualClock`으로 대체하여 몇 시간의 동작을 즉시 재현
+pub trait Clock: WallClock + Send + Sync {
+    /// 단조 증가하는 현재 시점 (Linux에서는 절전 중에 멈춤)
+    fn instant(&self) -> Instant;
+    /// 절전 시간을 포함하여 흐르는 시간 (`instant`와의 차이로 절전을 감지)
+    fn boottime(&self) -> Duration;
+    /// 지정한 시간만큼 대기
+    fn sleep(&self, duration: Duration);
+}
+
+/// 시스템 시각을 그대로 사용하는 시계
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl WallClock for SystemClock {
+    fn now(&self) -> DateTime<Local> {
+        Local::now()
+    }
+}
+
+impl Clock for SystemClock {
+    fn instant(&self) -> Instant {
+        Instant::now()
+    }
+
+    fn boottime(&self) -> Duration {
+        #[cfg(target_os = "linux")]
+        {
+            let mut ts = libc::timespec {
+                tv_sec: 0,
+                tv_nsec: 0,
+            };
+            if unsafe { libc::clock_gettime(libc::CLOCK_BOOTTIME, &mut ts) } == 0 {
+                return Duration::new(ts.tv_sec as u64, ts.tv_nsec as u32);
+            }
+        }
+
+        // CLOCK_BOOTTIME이 없는 플랫폼은 벽시계로 대신함
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+    }
+
+    fn sleep(&self, duration: Duration) {
+        std::thread::sleep(duration);
+    }
+}
+
+/// 테스트 기준일(2024-03-04 월요일)의 지정한 시각
+#[cfg(test)]
+pub fn test_time(hour: u32, minute: u32) -> DateTime<Local> {
+    use chrono::TimeZone;
+
+    Local.with_ymd_and_hms(2024, 3, 4, hour, minute, 0).unwrap()
+}
+
+/// 직접 시간을 진행시키는 시계 (`sleep`은 기다리지 않고 시간만 진행)
+#[cfg(test)]
+pub struct ManualClock {
+    base_instant: Instant,
+    base_time: DateTime<Local>,
+    offset: std::sync::Mutex<Duration>,
+    suspended: std::sync::Mutex<Duration>,
+}
+
+#[cfg(test)]
+impl ManualClock {
+    pub fn new(start: DateTime<Local>) -> Self {
+        Self {
+            base_instant: Instant::now(),
+            base_time: start,
+            offset: std::sync::Mutex::new(Duration::ZERO),
+            suspended: std::sync::Mutex::new(Duration::ZERO),
+        }
+    }
+
+    /// 테스트 기준일 09:00에서 시작하는 시계
+    pub fn monday_morning() -> Self {
+        Self::new(test_time(9, 0))
+    }
+
+    /// 절전을 흉내 냄 (벽시계와 boottime만 진행하고 단조 시간은 멈춤)
+    pub fn suspend(&self, duration: Duration) {
+        *self.suspended.lock().unwrap() += duration;
+    }
+
+    pub fn advance(&self, duration: Duration) {
+        *self.offset.lock().unwrap() += duration;
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        *self.offset.lock().unwrap()
+    }
+
+    fn suspended(&self) -> Duration {
+        *self.suspended.lock().unwrap()
+    }
+}
+
+#[cfg(test)]
+impl WallClock for ManualClock {
+    fn now(&self) -> DateTime<Local> {
+        self.base_time + chrono::Duration::from_std(self.elapsed() + self.suspended()).unwrap()
+    }
+}
+
+#[cfg(test)]
+impl Clock for ManualClock {
+    fn instant(&self) -> Instant {
+        self.base_instant + self.elapsed()
+    }
+
+    fn boottime(&self) -> Duration {
+        self.elapsed() + self.suspended()
+    }
+
+    fn sleep(&self, duration: Duration) {
+        self.advance(duration);
+    }
+}
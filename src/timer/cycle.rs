@@ -0,0 +1,311 @@
+use super::clock::{Clock, SystemClock};
+use super::simple_timer::{SimpleTimer, TimerState};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// 작업/휴식 주기(뽀모도로) 설정
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CycleSettings {
+    pub enabled: bool,
+    pub work_minutes: u32,
+    pub short_break_minutes: u32,
+    pub long_break_minutes: u32,
+    /// 작업을 이 횟수만큼 마칠 때마다 긴 휴식 (0이면 긴 휴식 없음)
+    pub long_break_every: u32,
+}
+
+impl Default for CycleSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            work_minutes: 25,
+            short_break_minutes: 5,
+            long_break_minutes: 15,
+            long_break_every: 4,
+        }
+    }
+}
+
+impl CycleSettings {
+    /// 구간 길이 (0분 구간은 연달아 넘어가지 않도록 최소 1분)
+    pub fn minutes_for(&self, phase: CyclePhase) -> u32 {
+        let minutes = match phase {
+            CyclePhase::Work => self.work_minutes,
+            CyclePhase::ShortBreak => self.short_break_minutes,
+            CyclePhase::LongBreak => self.long_break_minutes,
+        };
+        minutes.max(1)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CyclePhase {
+    Work,
+    ShortBreak,
+    LongBreak,
+}
+
+impl CyclePhase {
+    pub fn label(&self) -> &'static str {
+        match self {
+            CyclePhase::Work => "작업",
+            CyclePhase::ShortBreak => "짧은 휴식",
+            CyclePhase::LongBreak => "긴 휴식",
+        }
+    }
+
+    /// 휴식 중에는 동작을 멈춰 화면이 잠길 수 있도록 함
+    pub fn is_break(&self) -> bool {
+        !matches!(self, CyclePhase::Work)
+    }
+}
+
+/// 작업과 휴식을 번갈아 진행하는 주기 타이머
+///
+/// 설정 변경은 다음 구간부터 반영됨
+pub struct WorkCycle {
+    phase: CyclePhase,
+    completed: u32,
+    timer: SimpleTimer,
+}
+
+impl WorkCycle {
+    pub fn new() -> Self {
+        Self::with_clock(Arc::new(SystemClock))
+    }
+
+    pub fn with_clock(clock: Arc<dyn Clock>) -> Self {
+        Self {
+            phase: CyclePhase::Work,
+            completed: 0,
+            timer: SimpleTimer::with_clock(0, clock),
+        }
+    }
+
+    /// 첫 작업 구간부터 시작
+    pub fn start(&mut self, settings: &CycleSettings) {
+        self.completed = 0;
+        self.enter(CyclePhase::Work, settings);
+    }
+
+    pub fn stop(&mut self) {
+        self.timer.stop();
+        self.phase = CyclePhase::Work;
+        self.completed = 0;
+    }
+
+    pub fn pause(&mut self) {
+        self.timer.pause();
+    }
+
+    pub fn resume(&mut self) {
+        self.timer.resume();
+    }
+
+    /// 절전 시간을 현재 구간에 포함 (놓친 구간은 건너뛰고 다음 구간 하나로만 넘어감)
+    pub fn add_elapsed(&mut self, duration: Duration) {
+        self.timer.add_elapsed(duration);
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.timer.get_state() != TimerState::Stopped
+    }
+
+    /// 현재 구간이 끝났으면 다음 구간으로 넘어가고 새 구간을 반환
+    pub fn poll(&mut self, settings: &CycleSettings) -> Option<CyclePhase> {
+        if !self.timer.is_expired() {
+            return None;
+        }
+
+        if self.phase == CyclePhase::Work {
+            self.completed += 1;
+        }
+        let next = next_phase(self.phase, self.completed, settings);
+        self.enter(next, settings);
+        Some(next)
+    }
+
+    pub fn phase(&self) -> CyclePhase {
+        self.phase
+    }
+
+    /// 마친 작업 구간 수
+    pub fn completed(&self) -> u32 {
+        self.completed
+    }
+
+    pub fn remaining_seconds(&self) -> u64 {
+        self.timer.get_remaining_seconds()
+    }
+
+    fn enter(&mut self, phase: CyclePhase, settings: &CycleSettings) {
+        self.phase = phase;
+        self.timer.set_duration(settings.minutes_for(phase));
+        self.timer.start();
+    }
+}
+
+impl Default for WorkCycle {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// 현재 구간을 마친 뒤의 구간 (`completed`는 지금까지 마친 작업 구간 수)
+fn next_phase(phase: CyclePhase, completed: u32, settings: &CycleSettings) -> CyclePhase {
+    match phase {
+        // long_break_every가 0이면 None이 되어 긴 휴식 없음
+        CyclePhase::Work if completed.checked_rem(settings.long_break_every) == Some(0) => {
+            CyclePhase::LongBreak
+        }
+        CyclePhase::Work => CyclePhase::ShortBreak,
+        CyclePhase::ShortBreak | CyclePhase::LongBreak => CyclePhase::Work,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::timer::clock::ManualClock;
+
+    fn manual_cycle() -> (Arc<ManualClock>, WorkCycle) {
+        let clock = Arc::new(ManualClock::monday_morning());
+        let cycle = WorkCycle::with_clock(clock.clone());
+        (clock, cycle)
+    }
+
+    /// 1초씩 진행하며 구간이 바뀐 시점(분)과 새 구간을 기록
+    fn simulate(
+        clock: &ManualClock,
+        cycle: &mut WorkCycle,
+        settings: &CycleSettings,
+        minutes: u64,
+    ) -> Vec<(u64, CyclePhase)> {
+        let mut transitions = Vec::new();
+        for second in 1..=minutes * 60 {
+            clock.advance(Duration::from_secs(1));
+            if let Some(phase) = cycle.poll(settings) {
+                transitions.push((second / 60, phase));
+            }
+        }
+        transitions
+    }
+
+    #[test]
+    fn long_break_after_every_n_work_periods() {
+        let settings = CycleSettings::default();
+        let breaks: Vec<CyclePhase> = (1..=8)
+            .map(|completed| next_phase(CyclePhase::Work, completed, &settings))
+            .collect();
+        assert_eq!(breaks[3], CyclePhase::LongBreak);
+        assert_eq!(breaks[7], CyclePhase::LongBreak);
+        assert_eq!(
+            breaks
+                .iter()
+                .filter(|phase| **phase == CyclePhase::ShortBreak)
+                .count(),
+            6
+        );
+
+        for phase in [CyclePhase::ShortBreak, CyclePhase::LongBreak] {
+            assert_eq!(next_phase(phase, 4, &settings), CyclePhase::Work);
+        }
+    }
+
+    #[test]
+    fn zero_long_break_every_uses_short_breaks_only() {
+        let settings = CycleSettings {
+            long_break_every: 0,
+            ..CycleSettings::default()
+        };
+        assert!((1..=20).all(|completed| {
+            next_phase(CyclePhase::Work, completed, &settings) == CyclePhase::ShortBreak
+        }));
+    }
+
+    #[test]
+    fn zero_minute_phase_lasts_one_minute() {
+        let settings = CycleSettings {
+            short_break_minutes: 0,
+            ..CycleSettings::default()
+        };
+        assert_eq!(settings.minutes_for(CyclePhase::ShortBreak), 1);
+        assert_eq!(settings.minutes_for(CyclePhase::Work), 25);
+    }
+
+    #[test]
+    fn start_and_stop_reset_the_cycle() {
+        let settings = CycleSettings::default();
+        let mut cycle = WorkCycle::new();
+        assert!(!cycle.is_running());
+
+        cycle.start(&settings);
+        assert!(cycle.is_running());
+        assert_eq!(cycle.phase(), CyclePhase::Work);
+        assert_eq!(cycle.poll(&settings), None);
+        assert!(cycle.remaining_seconds() > 24 * 60);
+
+        cycle.stop();
+        assert!(!cycle.is_running());
+        assert_eq!(cycle.completed(), 0);
+    }
+
+    #[test]
+    fn simulated_transitions_follow_phase_lengths() {
+        let settings = CycleSettings {
+            enabled: true,
+            ..CycleSettings::default()
+        };
+        let (clock, mut cycle) = manual_cycle();
+        cycle.start(&settings);
+
+        // 25+5 x3, 25+15 = 130분, 이후 다시 작업
+        let transitions = simulate(&clock, &mut cycle, &settings, 135);
+        let expected = [
+            (25, CyclePhase::ShortBreak),
+            (30, CyclePhase::Work),
+            (55, CyclePhase::ShortBreak),
+            (60, CyclePhase::Work),
+            (85, CyclePhase::ShortBreak),
+            (90, CyclePhase::Work),
+            (115, CyclePhase::LongBreak),
+            (130, CyclePhase::Work),
+        ];
+        assert_eq!(transitions, expected);
+        assert_eq!(cycle.completed(), 4);
+    }
+
+    #[test]
+    fn simulated_day_without_long_breaks() {
+        let settings = CycleSettings {
+            enabled: true,
+            long_break_every: 0,
+            ..CycleSettings::default()
+        };
+        let (clock, mut cycle) = manual_cycle();
+        cycle.start(&settings);
+
+        let transitions = simulate(&clock, &mut cycle, &settings, 8 * 60);
+        assert!(transitions
+            .iter()
+            .all(|(_, phase)| *phase != CyclePhase::LongBreak));
+        assert_eq!(cycle.completed(), 16);
+    }
+
+    #[test]
+    fn paused_cycle_does_not_advance() {
+        let settings = CycleSettings::default();
+        let (clock, mut cycle) = manual_cycle();
+        cycle.start(&settings);
+
+        clock.advance(Duration::from_secs(20 * 60));
+        cycle.pause();
+        assert!(simulate(&clock, &mut cycle, &settings, 60).is_empty());
+
+        cycle.resume();
+        let transitions = simulate(&clock, &mut cycle, &settings, 5);
+        assert_eq!(transitions, [(5, CyclePhase::ShortBreak)]);
+    }
+}
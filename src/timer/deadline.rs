@@ -0,0 +1,119 @@
+use chrono::{DateTime, Duration, NaiveTime, TimeZone};
+
+/// 지정한 현지 시각이 다음으로 돌아오는 시점 (이미 지났으면 다음 날)
+///
+/// 서머타임 전환으로 같은 시각이 두 번 있으면 `now` 이후의 첫 번째를,
+/// 시각이 존재하지 않으면 전환 직후의 시각을 사용함
+pub fn next_deadline<Tz: TimeZone>(now: &DateTime<Tz>, time: NaiveTime) -> DateTime<Tz> {
+    let timezone = now.timezone();
+    let mut date = now.date_naive();
+
+    loop {
+        let mut naive = date.and_time(time);
+
+        // 존재하지 않는 시각(서머타임 시작)은 존재하는 시각이 나올 때까지 뒤로 미룸
+        for _ in 0..8 {
+            let candidates: Vec<DateTime<Tz>> = timezone
+                .from_local_datetime(&naive)
+                .earliest()
+                .into_iter()
+                .chain(timezone.from_local_datetime(&naive).latest())
+                .collect();
+            if candidates.is_empty() {
+                naive += Duration::minutes(15);
+                continue;
+            }
+
+            if let Some(deadline) = candidates.into_iter().find(|candidate| candidate > now) {
+                return deadline;
+            }
+            break;
+        }
+
+        match date.succ_opt() {
+            Some(next) => date = next,
+            // 표현할 수 있는 마지막 날짜를 넘으면 바로 만료되도록 지금을 기한으로 사용
+            None => return now.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{FixedOffset, NaiveDate, Utc};
+    use chrono_tz::America::New_York;
+
+    fn time(hour: u32, minute: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(hour, minute, 0).unwrap()
+    }
+
+    #[test]
+    fn later_today_or_tomorrow_in_utc() {
+        let now = Utc.with_ymd_and_hms(2024, 3, 4, 9, 0, 0).unwrap();
+        assert_eq!(
+            next_deadline(&now, time(17, 30)),
+            Utc.with_ymd_and_hms(2024, 3, 4, 17, 30, 0).unwrap()
+        );
+        assert_eq!(
+            next_deadline(&now, time(8, 0)),
+            Utc.with_ymd_and_hms(2024, 3, 5, 8, 0, 0).unwrap()
+        );
+        // 지금과 같은 시각은 이미 지난 것으로 봄
+        assert_eq!(
+            next_deadline(&now, time(9, 0)),
+            Utc.with_ymd_and_hms(2024, 3, 5, 9, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn uses_the_local_date_of_a_fixed_offset() {
+        let seoul = FixedOffset::east_opt(9 * 3600).unwrap();
+        let now = seoul.with_ymd_and_hms(2024, 3, 4, 23, 30, 0).unwrap();
+        let deadline = next_deadline(&now, time(0, 15));
+        assert_eq!(
+            deadline,
+            seoul.with_ymd_and_hms(2024, 3, 5, 0, 15, 0).unwrap()
+        );
+        assert_eq!(deadline - now, Duration::minutes(45));
+    }
+
+    #[test]
+    fn skipped_time_moves_past_spring_forward() {
+        // 2024-03-10 02:00 EST에 03:00 EDT로 넘어감
+        let now = New_York.with_ymd_and_hms(2024, 3, 10, 1, 0, 0).unwrap();
+        let deadline = next_deadline(&now, time(2, 30));
+        assert_eq!(
+            deadline,
+            New_York.with_ymd_and_hms(2024, 3, 10, 3, 0, 0).unwrap()
+        );
+        assert_eq!(deadline - now, Duration::hours(1));
+    }
+
+    #[test]
+    fn repeated_time_picks_the_first_after_now() {
+        // 2024-11-03 01:00~02:00 EDT 뒤에 같은 시각이 EST로 한 번 더 옴
+        let first = New_York
+            .with_ymd_and_hms(2024, 11, 3, 1, 30, 0)
+            .earliest()
+            .unwrap();
+        let second = New_York
+            .with_ymd_and_hms(2024, 11, 3, 1, 30, 0)
+            .latest()
+            .unwrap();
+
+        let before = New_York.with_ymd_and_hms(2024, 11, 3, 0, 30, 0).unwrap();
+        assert_eq!(next_deadline(&before, time(1, 30)), first);
+
+        let between = first + Duration::minutes(15);
+        assert_eq!(next_deadline(&between, time(1, 30)), second);
+        assert_eq!(second - between, Duration::minutes(45));
+    }
+
+    #[test]
+    fn last_representable_date_does_not_panic() {
+        let last = NaiveDate::MAX.and_time(time(23, 59));
+        let now = Utc.from_utc_datetime(&last);
+        assert_eq!(next_deadline(&now, time(0, 0)), now);
+    }
+}
@@ -0,0 +1,150 @@
+use crate::monitor::session;
+use crate::notify;
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::process::Command;
+
+/// 타이머가 만료되었을 때 순서대로 수행할 동작
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ExpiryAction {
+    /// 자리비움 방지 동작 중지
+    Stop,
+    /// 셸 명령 실행
+    RunCommand { command: String },
+    /// 데스크톱 알림
+    Notify { message: String },
+    /// logind로 화면 잠금
+    LockScreen,
+    /// logind로 절전 모드 전환
+    Suspend,
+    /// 프로그램 종료
+    Quit,
+}
+
+impl ExpiryAction {
+    /// 선택 목록에 표시할 기본값
+    pub fn templates() -> [ExpiryAction; 6] {
+        [
+            ExpiryAction::Stop,
+            ExpiryAction::RunCommand {
+                command: String::new(),
+            },
+            ExpiryAction::Notify {
+                message: "타이머가 만료되었습니다".to_string(),
+            },
+            ExpiryAction::LockScreen,
+            ExpiryAction::Suspend,
+            ExpiryAction::Quit,
+        ]
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ExpiryAction::Stop => "중지",
+            ExpiryAction::RunCommand { .. } => "명령 실행",
+            ExpiryAction::Notify { .. } => "알림",
+            ExpiryAction::LockScreen => "화면 잠금",
+            ExpiryAction::Suspend => "절전 모드",
+            ExpiryAction::Quit => "프로그램 종료",
+        }
+    }
+
+    pub fn same_kind(&self, other: &ExpiryAction) -> bool {
+        std::mem::discriminant(self) == std::mem::discriminant(other)
+    }
+}
+
+/// 중지나 종료가 없어 만료된 뒤에도 계속 동작하는 목록인지
+pub fn keeps_running(actions: &[ExpiryAction]) -> bool {
+    !actions
+        .iter()
+        .any(|action| matches!(action, ExpiryAction::Stop | ExpiryAction::Quit))
+}
+
+/// 앱 상태와 무관한 동작을 수행 (중지와 종료는 호출하는 쪽에서 처리)
+pub fn execute(action: &ExpiryAction) -> Result<()> {
+    match action {
+        ExpiryAction::RunCommand { command } => run_command(command),
+        ExpiryAction::Notify { message } => notify::send_notification("Stay awake", message),
+        ExpiryAction::LockScreen => session::lock_session(),
+        ExpiryAction::Suspend => session::suspend(),
+        ExpiryAction::Stop | ExpiryAction::Quit => Ok(()),
+    }
+}
+
+fn run_command(command: &str) -> Result<()> {
+    if command.trim().is_empty() {
+        return Err(anyhow::anyhow!("실행할 명령이 비어 있습니다"));
+    }
+
+    #[cfg(windows)]
+    let mut child = Command::new("cmd").args(["/C", command]).spawn()?;
+    #[cfg(not(windows))]
+    let mut child = Command::new("sh").args(["-c", command]).spawn()?;
+
+    // GUI가 멈추지 않도록 종료 결과는 별도 스레드에서 기록
+    let command = command.to_string();
+    std::thread::spawn(move || match child.wait() {
+        Ok(status) if status.success() => log::info!("만료 명령 완료: {}", command),
+        Ok(status) => log::warn!("만료 명령 실패 ({}): {}", status, command),
+        Err(e) => log::warn!("만료 명령 대기 실패 ({}): {}", e, command),
+    });
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn actions_round_trip_through_serde() {
+        for action in ExpiryAction::templates() {
+            let json = serde_json::to_string(&action).unwrap();
+            let parsed: ExpiryAction = serde_json::from_str(&json).unwrap();
+            assert_eq!(parsed, action);
+        }
+
+        let action: ExpiryAction =
+            serde_json::from_str(r#"{"type": "run_command", "command": "make backup"}"#).unwrap();
+        assert_eq!(
+            action,
+            ExpiryAction::RunCommand {
+                command: "make backup".to_string()
+            }
+        );
+        assert_eq!(
+            serde_json::to_string(&ExpiryAction::LockScreen).unwrap(),
+            r#"{"type":"lock_screen"}"#
+        );
+        assert!(serde_json::from_str::<ExpiryAction>(r#"{"type": "reboot"}"#).is_err());
+    }
+
+    #[test]
+    fn failing_command_is_reported() {
+        let empty = ExpiryAction::RunCommand {
+            command: "  ".to_string(),
+        };
+        assert_eq!(
+            execute(&empty).unwrap_err().to_string(),
+            "실행할 명령이 비어 있습니다"
+        );
+
+        // 실행된 명령의 종료 코드는 기다리지 않고 기록만 함
+        let exits_with_error = ExpiryAction::RunCommand {
+            command: "exit 3".to_string(),
+        };
+        assert!(execute(&exits_with_error).is_ok());
+    }
+
+    #[test]
+    fn list_without_stop_or_quit_keeps_running() {
+        assert!(keeps_running(&[]));
+        assert!(keeps_running(&[ExpiryAction::LockScreen]));
+        assert!(!keeps_running(&[
+            ExpiryAction::LockScreen,
+            ExpiryAction::Quit
+        ]));
+        assert!(!keeps_running(&[ExpiryAction::Stop]));
+    }
+}
@@ -1,3 +1,15 @@
+pub mod clock;
+pub mod cycle;
+pub mod deadline;
+pub mod expiry;
+pub mod persist;
 pub mod simple_timer;
+pub mod snooze;
+pub mod suspend;
 
+pub use clock::{Clock, SystemClock, WallClock};
+pub use cycle::{CycleSettings, WorkCycle};
+pub use deadline::next_deadline;
+pub use expiry::ExpiryAction;
 pub use simple_timer::SimpleTimer;
+pub use suspend::SuspendDetector;
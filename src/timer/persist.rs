@@ -0,0 +1,146 @@
+use super::simple_timer::{SimpleTimer, TimerState};
+use crate::config::backup;
+use crate::config::Settings;
+use anyhow::Result;
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+
+const STATE_FILE: &str = "timer_state.json";
+
+/// 재시작 후 이어서 진행하기 위해 저장하는 타이머 상태
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "state", rename_all = "snake_case")]
+pub enum SavedTimer {
+    /// 동작 중이던 타이머는 종료 시각(벽시계)을 저장
+    Running { deadline: DateTime<Local> },
+    /// 일시정지 중이던 타이머는 남은 시간을 저장
+    Paused { remaining_seconds: u64 },
+}
+
+impl SavedTimer {
+    /// 동작 중이거나 일시정지된 타이머의 상태 (그 외에는 None)
+    pub fn capture(timer: &SimpleTimer) -> Option<Self> {
+        match timer.get_state() {
+            TimerState::Running => timer
+                .get_deadline()
+                .map(|deadline| SavedTimer::Running { deadline }),
+            TimerState::Paused => Some(SavedTimer::Paused {
+                remaining_seconds: timer.get_remaining_seconds(),
+            }),
+            TimerState::Stopped | TimerState::Expired => None,
+        }
+    }
+
+    /// 남은 시간 (종료 시각이 이미 지났으면 None)
+    pub fn remaining(&self, now: DateTime<Local>) -> Option<Duration> {
+        match self {
+            SavedTimer::Running { deadline } => (*deadline - now).to_std().ok(),
+            SavedTimer::Paused { remaining_seconds } => {
+                Some(Duration::from_secs(*remaining_seconds))
+            }
+        }
+        .filter(|remaining| !remaining.is_zero())
+    }
+
+    pub fn describe(&self) -> String {
+        match self {
+            SavedTimer::Running { deadline } => {
+                format!("{}까지 동작 중", deadline.format("%m-%d %H:%M"))
+            }
+            SavedTimer::Paused { remaining_seconds } => format!(
+                "{}분 {}초 남은 채 일시정지",
+                remaining_seconds / 60,
+                remaining_seconds % 60
+            ),
+        }
+    }
+}
+
+fn state_path() -> Result<PathBuf> {
+    Settings::sidecar_path(STATE_FILE)
+}
+
+/// 타이머 상태를 저장 (None이면 저장된 상태 삭제)
+pub fn save(saved: Option<&SavedTimer>) -> Result<()> {
+    let path = state_path()?;
+    match saved {
+        Some(saved) => {
+            backup::write_atomic(&path, serde_json::to_string_pretty(saved)?.as_bytes())?;
+        }
+        None if path.exists() => fs::remove_file(path)?,
+        None => {}
+    }
+    Ok(())
+}
+
+pub fn load() -> Result<Option<SavedTimer>> {
+    let path = state_path()?;
+    if !path.exists() {
+        return Ok(None);
+    }
+
+    let content = fs::read_to_string(path)?;
+    Ok(Some(serde_json::from_str(&content)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::timer::clock::{test_time as at, ManualClock};
+    use crate::timer::WallClock;
+    use std::sync::Arc;
+
+    #[test]
+    fn running_timer_is_saved_as_deadline() {
+        let clock = Arc::new(ManualClock::monday_morning());
+        let mut timer = SimpleTimer::with_clock(90, clock.clone());
+        timer.start();
+        clock.advance(Duration::from_secs(30 * 60));
+
+        let saved = SavedTimer::capture(&timer).unwrap();
+        assert_eq!(
+            saved,
+            SavedTimer::Running {
+                deadline: at(10, 30)
+            }
+        );
+
+        // 재시작 후에는 벽시계 기준으로 남은 시간을 계산
+        assert_eq!(
+            saved.remaining(at(10, 0)),
+            Some(Duration::from_secs(30 * 60))
+        );
+        assert_eq!(saved.remaining(at(10, 30)), None);
+        assert_eq!(saved.remaining(at(12, 0)), None);
+    }
+
+    #[test]
+    fn paused_timer_keeps_remaining_time() {
+        let clock = Arc::new(ManualClock::monday_morning());
+        let mut timer = SimpleTimer::with_clock(60, clock.clone());
+        timer.start();
+        clock.advance(Duration::from_secs(15 * 60));
+        timer.pause();
+
+        let saved = SavedTimer::capture(&timer).unwrap();
+        let json = serde_json::to_string(&saved).unwrap();
+        assert_eq!(json, r#"{"state":"paused","remaining_seconds":2700}"#);
+
+        let loaded: SavedTimer = serde_json::from_str(&json).unwrap();
+        let later = clock.now() + chrono::Duration::days(2);
+        assert_eq!(loaded.remaining(later), Some(Duration::from_secs(45 * 60)));
+    }
+
+    #[test]
+    fn stopped_timer_is_not_saved() {
+        let mut timer = SimpleTimer::new(10);
+        assert_eq!(SavedTimer::capture(&timer), None);
+
+        timer.start();
+        timer.stop();
+        assert_eq!(SavedTimer::capture(&timer), None);
+    }
+}
@@ -1,71 +1,184 @@
+use super::clock::{Clock, SystemClock};
+use chrono::{DateTime, Local};
+use std::sync::Arc;
 use std::time::{Duration, Instant};
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum TimerState {
     Stopped,
     Running,
+    Paused,
     Expired,
 }
 
 pub struct SimpleTimer {
     duration: Duration,
     start_time: Option<Instant>,
+    // 일시정지 전까지 누적된 경과 시간
+    elapsed_before: Duration,
+    // 종료 시각 지정 모드에서의 목표 시각
+    deadline: Option<DateTime<Local>>,
     state: TimerState,
+    clock: Arc<dyn Clock>,
 }
 
 impl SimpleTimer {
     pub fn new(minutes: u32) -> Self {
+        Self::with_clock(minutes, Arc::new(SystemClock))
+    }
+
+    pub fn with_clock(minutes: u32, clock: Arc<dyn Clock>) -> Self {
         Self {
             duration: Duration::from_secs(minutes as u64 * 60),
             start_time: None,
+            elapsed_before: Duration::ZERO,
+            deadline: None,
             state: TimerState::Stopped,
+            clock,
         }
     }
 
     pub fn start(&mut self) {
-        self.start_time = Some(Instant::now());
+        self.start_time = Some(self.clock.instant());
+        self.elapsed_before = Duration::ZERO;
+        self.deadline = None;
         self.state = TimerState::Running;
     }
 
+    /// 지정한 시간 뒤에 만료되도록 시작
+    pub fn start_for(&mut self, duration: Duration) {
+        self.duration = duration;
+        self.start();
+    }
+
+    /// 지정한 시각에 만료되도록 시작
+    pub fn start_until(&mut self, deadline: DateTime<Local>) {
+        self.start();
+        self.set_deadline(deadline);
+    }
+
     pub fn stop(&mut self) {
         self.start_time = None;
+        self.elapsed_before = Duration::ZERO;
+        self.deadline = None;
         self.state = TimerState::Stopped;
     }
 
+    /// 경과 시간을 유지한 채 일시정지
+    pub fn pause(&mut self) {
+        if self.state != TimerState::Running {
+            return;
+        }
+
+        self.elapsed_before = self.elapsed();
+        self.start_time = None;
+        self.state = TimerState::Paused;
+    }
+
+    pub fn resume(&mut self) {
+        if self.state != TimerState::Paused {
+            return;
+        }
+
+        self.start_time = Some(self.clock.instant());
+        self.state = TimerState::Running;
+        // 시간 지정 모드는 멈춰 있던 만큼 종료 시각이 뒤로 밀리지만, 종료 시각 모드는
+        // 멈춰 있던 시간과 관계없이 같은 시각에 만료 (이미 지났으면 바로 만료)
+        if let Some(deadline) = self.deadline {
+            self.set_deadline(deadline);
+        }
+    }
+
     pub fn is_expired(&mut self) -> bool {
         if self.state != TimerState::Running {
             return false;
         }
 
-        if let Some(start) = self.start_time {
-            if start.elapsed() >= self.duration {
-                self.state = TimerState::Expired;
-                return true;
-            }
+        if self.elapsed() >= self.duration {
+            self.state = TimerState::Expired;
+            return true;
         }
         false
     }
 
     pub fn get_remaining_seconds(&self) -> u64 {
-        if let Some(start) = self.start_time {
-            let elapsed = start.elapsed();
-            if elapsed < self.duration {
-                return (self.duration - elapsed).as_secs();
-            }
+        if self.state == TimerState::Stopped {
+            return 0;
         }
-        0
+        self.duration.saturating_sub(self.elapsed()).as_secs()
     }
 
     pub fn get_state(&self) -> TimerState {
         self.state.clone()
     }
 
+    /// 경과 시간은 유지한 채 전체 시간만 변경 (이미 지난 경우 다음 확인 시 만료)
     pub fn set_duration(&mut self, minutes: u32) {
         self.duration = Duration::from_secs(minutes as u64 * 60);
+        self.deadline = None;
+    }
+
+    /// 단조 시간에 잡히지 않은 시간(절전 등)을 경과 시간에 더함
+    pub fn add_elapsed(&mut self, duration: Duration) {
         if self.state == TimerState::Running {
-            self.start_time = Some(Instant::now()); // 재시작
+            self.elapsed_before += duration;
+        }
+    }
+
+    /// 경과 시간은 유지한 채 만료 시점을 뒤로 미룸
+    pub fn extend(&mut self, extra: Duration) {
+        self.duration += extra;
+        if let Some(deadline) = self.deadline {
+            self.deadline = chrono::Duration::from_std(extra)
+                .ok()
+                .map(|extra| deadline + extra);
+        }
+    }
+
+    /// 경과 시간은 유지한 채 지정한 시각에 만료되도록 전체 시간을 변경
+    pub fn set_deadline(&mut self, deadline: DateTime<Local>) {
+        let remaining = (deadline - self.clock.now()).to_std().unwrap_or_default();
+        self.duration = self.elapsed() + remaining;
+        self.deadline = Some(deadline);
+    }
+
+    /// "17:30까지, 73분 0초 남음" 형식의 남은 시간
+    pub fn remaining_text(&self) -> String {
+        let remaining = self.get_remaining_seconds();
+        let minutes = remaining / 60;
+        let seconds = remaining % 60;
+
+        match self.get_deadline() {
+            Some(deadline) => format!(
+                "{}까지, {}분 {}초 남음",
+                deadline.format("%H:%M"),
+                minutes,
+                seconds
+            ),
+            None => format!("{}분 {}초 남음", minutes, seconds),
+        }
+    }
+
+    /// 만료 예정 시각 (동작 중이거나 일시정지 상태일 때)
+    pub fn get_deadline(&self) -> Option<DateTime<Local>> {
+        let remaining = self.duration.saturating_sub(self.elapsed());
+        let estimated = chrono::Duration::from_std(remaining)
+            .ok()
+            .map(|remaining| self.clock.now() + remaining);
+
+        match self.state {
+            // 일시정지 중에는 재개 시점에 따라 종료 시각이 달라짐 (종료 시각 모드는 그대로)
+            TimerState::Running | TimerState::Paused => self.deadline.or(estimated),
+            _ => None,
         }
     }
+
+    fn elapsed(&self) -> Duration {
+        let running = self.start_time.map_or(Duration::ZERO, |start| {
+            self.clock.instant().saturating_duration_since(start)
+        });
+        self.elapsed_before + running
+    }
 }
 
 impl Default for SimpleTimer {
@@ -73,3 +186,154 @@ impl Default for SimpleTimer {
         Self::new(60) // 기본 60분
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::timer::clock::{test_time, ManualClock};
+
+    const MINUTE: Duration = Duration::from_secs(60);
+
+    fn manual_timer(minutes: u32) -> (Arc<ManualClock>, SimpleTimer) {
+        let clock = Arc::new(ManualClock::monday_morning());
+        let timer = SimpleTimer::with_clock(minutes, clock.clone());
+        (clock, timer)
+    }
+
+    #[test]
+    fn pause_and_resume_only_from_matching_state() {
+        let mut timer = SimpleTimer::new(60);
+        timer.pause();
+        assert_eq!(timer.get_state(), TimerState::Stopped);
+
+        timer.start();
+        timer.resume();
+        assert_eq!(timer.get_state(), TimerState::Running);
+
+        timer.pause();
+        assert_eq!(timer.get_state(), TimerState::Paused);
+        assert!(!timer.is_expired());
+        assert!(timer.get_remaining_seconds() >= 59 * 60);
+
+        timer.resume();
+        assert_eq!(timer.get_state(), TimerState::Running);
+
+        timer.stop();
+        assert_eq!(timer.get_state(), TimerState::Stopped);
+        assert_eq!(timer.get_remaining_seconds(), 0);
+    }
+
+    #[test]
+    fn set_duration_keeps_state_and_elapsed_time() {
+        let mut timer = SimpleTimer::new(60);
+        timer.start();
+        timer.pause();
+
+        timer.set_duration(30);
+        assert_eq!(timer.get_state(), TimerState::Paused);
+        assert!(timer.get_remaining_seconds() <= 30 * 60);
+
+        // 이미 지난 시간으로 줄이면 다시 시작하지 않고 바로 만료
+        timer.resume();
+        timer.set_duration(0);
+        assert!(timer.is_expired());
+        assert_eq!(timer.get_state(), TimerState::Expired);
+    }
+
+    #[test]
+    fn expires_after_duration() {
+        let (clock, mut timer) = manual_timer(90);
+        timer.start();
+
+        clock.advance(89 * MINUTE);
+        assert!(!timer.is_expired());
+        assert_eq!(timer.get_remaining_seconds(), 60);
+
+        clock.advance(MINUTE);
+        assert!(timer.is_expired());
+        assert_eq!(timer.get_state(), TimerState::Expired);
+    }
+
+    #[test]
+    fn pause_does_not_consume_time() {
+        let (clock, mut timer) = manual_timer(60);
+        timer.start();
+        clock.advance(20 * MINUTE);
+
+        timer.pause();
+        clock.advance(3 * 60 * MINUTE);
+        assert!(!timer.is_expired());
+        assert_eq!(timer.get_remaining_seconds(), 40 * 60);
+
+        timer.resume();
+        clock.advance(40 * MINUTE);
+        assert!(timer.is_expired());
+    }
+
+    #[test]
+    fn extend_keeps_elapsed_time() {
+        let (clock, mut timer) = manual_timer(30);
+        timer.start();
+        clock.advance(25 * MINUTE);
+
+        timer.extend(15 * MINUTE);
+        assert_eq!(timer.get_remaining_seconds(), 20 * 60);
+
+        clock.advance(20 * MINUTE);
+        assert!(timer.is_expired());
+    }
+
+    #[test]
+    fn deadline_follows_manual_clock() {
+        let (clock, mut timer) = manual_timer(0);
+        let deadline = test_time(17, 30);
+        timer.start_until(deadline);
+
+        clock.advance(8 * 60 * MINUTE);
+        assert_eq!(timer.remaining_text(), "17:30까지, 30분 0초 남음");
+        assert!(!timer.is_expired());
+
+        clock.advance(30 * MINUTE);
+        assert!(timer.is_expired());
+    }
+
+    #[test]
+    fn pause_keeps_absolute_deadline() {
+        let (clock, mut timer) = manual_timer(0);
+        timer.start_until(test_time(17, 30));
+
+        // 화면 잠금으로 멈춰 있던 시간만큼 종료 시각이 밀리지 않음
+        clock.advance(60 * MINUTE);
+        timer.pause();
+        clock.advance(2 * 60 * MINUTE);
+        assert_eq!(timer.get_deadline(), Some(test_time(17, 30)));
+        timer.resume();
+        assert_eq!(timer.remaining_text(), "17:30까지, 330분 0초 남음");
+
+        clock.advance(330 * MINUTE - Duration::from_secs(1));
+        assert!(!timer.is_expired());
+        clock.advance(Duration::from_secs(1));
+        assert!(timer.is_expired());
+
+        // 잠겨 있는 동안 종료 시각이 지나면 잠금 해제 후 바로 만료
+        let (clock, mut timer) = manual_timer(0);
+        timer.start_until(test_time(10, 0));
+        timer.pause();
+        clock.advance(3 * 60 * MINUTE);
+        assert!(!timer.is_expired());
+        timer.resume();
+        assert!(timer.is_expired());
+    }
+
+    #[test]
+    fn remaining_text_while_paused() {
+        let (clock, mut timer) = manual_timer(120);
+        timer.start();
+        clock.advance(Duration::from_secs(45 * 60 + 30));
+        timer.pause();
+
+        // 일시정지 중에는 지금 재개한다고 가정한 종료 시각을 표시
+        clock.advance(60 * MINUTE);
+        assert_eq!(timer.remaining_text(), "12:00까지, 74분 30초 남음");
+    }
+}
@@ -0,0 +1,68 @@
+use crate::config::Settings;
+use anyhow::Result;
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+
+const HISTORY_FILE: &str = "snooze_history.jsonl";
+
+/// 타이머 연장 기록 한 건
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SnoozeRecord {
+    pub time: DateTime<Local>,
+    pub minutes: u32,
+    /// 연장한 곳 (gui, cli)
+    pub source: String,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SnoozeStats {
+    pub today: usize,
+    pub total: usize,
+    pub total_minutes: u64,
+}
+
+fn history_path() -> Result<PathBuf> {
+    Settings::sidecar_path(HISTORY_FILE)
+}
+
+/// 연장 기록을 한 줄(JSON)씩 덧붙여 저장
+pub fn record(minutes: u32, source: &str) -> Result<()> {
+    let path = history_path()?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    let record = SnoozeRecord {
+        time: Local::now(),
+        minutes,
+        source: source.to_string(),
+    };
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(file, "{}", serde_json::to_string(&record)?)?;
+    Ok(())
+}
+
+/// 저장된 연장 기록 통계 (읽을 수 없는 줄은 무시)
+pub fn stats() -> Result<SnoozeStats> {
+    let path = history_path()?;
+    if !path.exists() {
+        return Ok(SnoozeStats::default());
+    }
+
+    let today = Local::now().date_naive();
+    let mut stats = SnoozeStats::default();
+    for line in fs::read_to_string(path)?.lines() {
+        let Ok(record) = serde_json::from_str::<SnoozeRecord>(line) else {
+            continue;
+        };
+        stats.total += 1;
+        stats.total_minutes += record.minutes as u64;
+        if record.time.date_naive() == today {
+            stats.today += 1;
+        }
+    }
+    Ok(stats)
+}
@@ -0,0 +1,109 @@
+use super::clock::Clock;
+use std::time::{Duration, Instant};
+
+/// 이보다 짧은 차이는 측정 오차로 보고 무시
+const SUSPEND_THRESHOLD: Duration = Duration::from_secs(5);
+
+/// 단조 시간과 boottime(또는 벽시계)의 차이로 절전 구간을 감지
+///
+/// Linux의 `Instant`(CLOCK_MONOTONIC)는 절전 중에 멈추므로, 마지막 확인 이후
+/// boottime이 단조 시간보다 더 흘렀다면 그만큼 절전했던 것으로 판단
+pub struct SuspendDetector {
+    last_instant: Instant,
+    last_boottime: Duration,
+}
+
+impl SuspendDetector {
+    pub fn new(clock: &dyn Clock) -> Self {
+        Self {
+            last_instant: clock.instant(),
+            last_boottime: clock.boottime(),
+        }
+    }
+
+    /// 마지막 확인 이후 절전했으면 절전 시간을 반환
+    pub fn poll(&mut self, clock: &dyn Clock) -> Option<Duration> {
+        let instant = clock.instant();
+        let boottime = clock.boottime();
+
+        let monotonic = instant.saturating_duration_since(self.last_instant);
+        let total = boottime.saturating_sub(self.last_boottime);
+        self.last_instant = instant;
+        self.last_boottime = boottime;
+
+        let gap = total.saturating_sub(monotonic);
+        (gap >= SUSPEND_THRESHOLD).then_some(gap)
+    }
+}
+
+/// "1시간 5분 3초" 형식
+pub fn format_duration(duration: Duration) -> String {
+    let secs = duration.as_secs();
+    let (hours, minutes, seconds) = (secs / 3600, secs / 60 % 60, secs % 60);
+    if hours > 0 {
+        format!("{}시간 {}분 {}초", hours, minutes, seconds)
+    } else if minutes > 0 {
+        format!("{}분 {}초", minutes, seconds)
+    } else {
+        format!("{}초", seconds)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::timer::clock::ManualClock;
+    use crate::timer::SimpleTimer;
+    use std::sync::Arc;
+
+    const MINUTE: Duration = Duration::from_secs(60);
+
+    #[test]
+    fn detects_suspend_gap_once() {
+        let clock = Arc::new(ManualClock::monday_morning());
+        let mut detector = SuspendDetector::new(clock.as_ref());
+
+        clock.advance(10 * MINUTE);
+        assert_eq!(detector.poll(clock.as_ref()), None);
+
+        clock.advance(MINUTE);
+        clock.suspend(95 * MINUTE);
+        clock.advance(MINUTE);
+        assert_eq!(detector.poll(clock.as_ref()), Some(95 * MINUTE));
+        assert_eq!(detector.poll(clock.as_ref()), None);
+    }
+
+    #[test]
+    fn ignores_small_drift() {
+        let clock = Arc::new(ManualClock::monday_morning());
+        let mut detector = SuspendDetector::new(clock.as_ref());
+
+        clock.suspend(Duration::from_secs(2));
+        assert_eq!(detector.poll(clock.as_ref()), None);
+    }
+
+    #[test]
+    fn sleep_counts_toward_timer_only_when_added() {
+        let clock = Arc::new(ManualClock::monday_morning());
+        let mut counted = SimpleTimer::with_clock(60, clock.clone());
+        let mut excluded = SimpleTimer::with_clock(60, clock.clone());
+        counted.start();
+        excluded.start();
+        let mut detector = SuspendDetector::new(clock.as_ref());
+
+        clock.advance(20 * MINUTE);
+        clock.suspend(30 * MINUTE);
+        let gap = detector.poll(clock.as_ref()).unwrap();
+        counted.add_elapsed(gap);
+
+        assert_eq!(counted.get_remaining_seconds(), 10 * 60);
+        assert_eq!(excluded.get_remaining_seconds(), 40 * 60);
+    }
+
+    #[test]
+    fn formats_durations() {
+        assert_eq!(format_duration(Duration::from_secs(42)), "42초");
+        assert_eq!(format_duration(Duration::from_secs(125)), "2분 5초");
+        assert_eq!(format_duration(Duration::from_secs(3903)), "1시간 5분 3초");
+    }
+}